@@ -1,11 +1,17 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
-use teloxide::prelude::ChatId;
+use teloxide::prelude::{ChatId, UserId};
+use teloxide::utils::html;
 use tokio::sync::RwLock;
 
-use crate::State;
+use crate::collection::Collection;
+use crate::poll::CelebrationPoll;
+use crate::schedule::ScheduledEvent;
+use crate::wishlist::WishlistItem;
+use crate::{EventKind, Language, Settings, State};
 
 /// The limit size of the birthdays map in bytes.
 pub const BIRTHDAY_MAP_LIMIT: usize = 256 * 1024 * 1024;
@@ -36,12 +42,32 @@ impl Error {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BirthdaysMap {
     map: HashMap<ChatId, (State, Birthdays)>,
+    /// The map of chat IDs to chat settings.
+    #[serde(default)]
+    settings: HashMap<ChatId, Settings>,
+    /// The map of chat IDs to open gift collections.
+    #[serde(default)]
+    collections: HashMap<ChatId, Vec<Collection>>,
+    /// The map of Telegram user IDs to wishlists.
+    #[serde(default, deserialize_with = "crate::wishlist::deserialize_wishlists")]
+    wishlists: HashMap<UserId, Vec<WishlistItem>>,
+    /// The map of chat IDs to one-off and recurring scheduled events.
+    #[serde(default)]
+    events: HashMap<ChatId, Vec<ScheduledEvent>>,
+    /// The map of chat IDs to polls about the celebrations of upcoming birthdays.
+    #[serde(default)]
+    polls: HashMap<ChatId, Vec<CelebrationPoll>>,
 }
 
 impl Default for BirthdaysMap {
     fn default() -> Self {
         Self {
             map: HashMap::new(),
+            settings: HashMap::new(),
+            collections: HashMap::new(),
+            wishlists: HashMap::new(),
+            events: HashMap::new(),
+            polls: HashMap::new(),
         }
     }
 }
@@ -57,7 +83,14 @@ impl BirthdaysMap {
     ///
     /// A new map of chat IDs to bot states and birthdays.
     pub fn new(map: HashMap<ChatId, (State, Birthdays)>) -> Self {
-        Self { map }
+        Self {
+            map,
+            settings: HashMap::new(),
+            collections: HashMap::new(),
+            wishlists: HashMap::new(),
+            events: HashMap::new(),
+            polls: HashMap::new(),
+        }
     }
 
     /// Returns an iterator over the map of chat IDs to bot states and birthdays.
@@ -241,6 +274,385 @@ impl BirthdaysMap {
         self.map.get_mut(chat_id)
     }
 
+    /// Return the settings for the given chat ID.
+    /// If the chat ID is not present in the settings map, the default settings are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    ///
+    /// # Returns
+    ///
+    /// The settings for the given chat ID.
+    pub fn get_settings(&self, chat_id: &ChatId) -> Settings {
+        self.settings.get(chat_id).cloned().unwrap_or_default()
+    }
+
+    /// Updates the settings for the given chat ID.
+    /// If the chat ID is not present in the settings map, it will be added with the new settings.
+    /// If amount of memory used by the map exceeds the limit, an error will be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `settings` - The new settings.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success or failure of the operation.
+    pub fn update_settings(&mut self, chat_id: &ChatId, settings: Settings) -> Result<(), Error> {
+        if let Some(in_settings) = self.settings.get_mut(chat_id) {
+            *in_settings = settings;
+        } else {
+            if self.estimate_size()
+                + std::mem::size_of_val(chat_id)
+                + std::mem::size_of_val(&settings)
+                > BIRTHDAY_MAP_LIMIT
+            {
+                return Err(Error::new(ErrorKind::BirthdayMapFull));
+            } else {
+                self.settings.insert(*chat_id, settings);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the wishlist of the given Telegram user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The Telegram user ID.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the wishlist items, empty if there are none.
+    pub fn wishlist(&self, user_id: &UserId) -> &[WishlistItem] {
+        self.wishlists
+            .get(user_id)
+            .map_or(&[], |wishlist| wishlist.as_slice())
+    }
+
+    /// Updates the wishlist of the given Telegram user and assigns the IDs to the new items.
+    /// An empty wishlist is removed from the map.
+    /// If amount of memory used by the map exceeds the limit, an error will be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The Telegram user ID.
+    /// * `wishlist` - The new wishlist.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success or failure of the operation.
+    pub fn update_wishlist(
+        &mut self,
+        user_id: &UserId,
+        mut wishlist: Vec<WishlistItem>,
+    ) -> Result<(), Error> {
+        crate::wishlist::assign_ids(&mut wishlist);
+        if wishlist.is_empty() {
+            self.wishlists.remove(user_id);
+        } else if let Some(in_wishlist) = self.wishlists.get_mut(user_id) {
+            *in_wishlist = wishlist;
+        } else if self.estimate_size()
+            + std::mem::size_of_val(user_id)
+            + std::mem::size_of_val(wishlist.as_slice())
+            > BIRTHDAY_MAP_LIMIT
+        {
+            return Err(Error::new(ErrorKind::BirthdayMapFull));
+        } else {
+            self.wishlists.insert(*user_id, wishlist);
+        }
+        Ok(())
+    }
+
+    /// Returns the birthday linked to the given Telegram user in any chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The Telegram user ID.
+    ///
+    /// # Returns
+    ///
+    /// Some birthday if the user is linked to a birthday, otherwise None.
+    pub fn find_by_user(&self, user_id: UserId) -> Option<&Birthday> {
+        self.map
+            .values()
+            .flat_map(|(_, birthdays)| birthdays.iter())
+            .find(|birthday| birthday.kind.is_birthday() && birthday.user_id == Some(user_id))
+    }
+
+    /// Returns the open gift collections for the given chat ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the open collections, empty if there are none.
+    pub fn collections(&self, chat_id: &ChatId) -> &[Collection] {
+        self.collections
+            .get(chat_id)
+            .map_or(&[], |collections| collections.as_slice())
+    }
+
+    /// Return the mutable reference to the gift collection with the given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `id` - The ID of the collection.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the collection if it is open, otherwise `None`.
+    pub fn collection_mut(&mut self, chat_id: &ChatId, id: u32) -> Option<&mut Collection> {
+        self.collections
+            .get_mut(chat_id)?
+            .iter_mut()
+            .find(|collection| collection.id == id)
+    }
+
+    /// Adds the gift collection for the given chat ID and assigns it a unique ID.
+    /// If amount of memory used by the map exceeds the limit, an error will be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `collection` - The new collection.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with the ID of the collection, or an error if the map is full.
+    pub fn add_collection(
+        &mut self,
+        chat_id: &ChatId,
+        mut collection: Collection,
+    ) -> Result<u32, Error> {
+        if self.estimate_size()
+            + std::mem::size_of_val(chat_id)
+            + std::mem::size_of_val(&collection)
+            > BIRTHDAY_MAP_LIMIT
+        {
+            return Err(Error::new(ErrorKind::BirthdayMapFull));
+        }
+
+        let collections = self.collections.entry(*chat_id).or_default();
+        collection.id = collections
+            .iter()
+            .map(|collection| collection.id)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let id = collection.id;
+        collections.push(collection);
+
+        Ok(id)
+    }
+
+    /// Removes the gift collection with the given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `id` - The ID of the collection.
+    ///
+    /// # Returns
+    ///
+    /// The removed collection if it was open, otherwise `None`.
+    pub fn remove_collection(&mut self, chat_id: &ChatId, id: u32) -> Option<Collection> {
+        let collections = self.collections.get_mut(chat_id)?;
+        let idx = collections
+            .iter()
+            .position(|collection| collection.id == id)?;
+        let collection = collections.remove(idx);
+        if collections.is_empty() {
+            self.collections.remove(chat_id);
+        }
+        Some(collection)
+    }
+
+    /// Returns the scheduled events for the given chat ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the scheduled events, empty if there are none.
+    pub fn events(&self, chat_id: &ChatId) -> &[ScheduledEvent] {
+        self.events
+            .get(chat_id)
+            .map_or(&[], |events| events.as_slice())
+    }
+
+    /// Adds the scheduled event for the given chat ID and assigns it a unique ID.
+    /// If amount of memory used by the map exceeds the limit, an error will be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `event` - The new event.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with the ID of the event, or an error if the map is full.
+    pub fn add_event(&mut self, chat_id: &ChatId, mut event: ScheduledEvent) -> Result<u32, Error> {
+        if self.estimate_size() + std::mem::size_of_val(chat_id) + std::mem::size_of_val(&event)
+            > BIRTHDAY_MAP_LIMIT
+        {
+            return Err(Error::new(ErrorKind::BirthdayMapFull));
+        }
+
+        let events = self.events.entry(*chat_id).or_default();
+        event.id = events
+            .iter()
+            .map(|event| event.id)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        let id = event.id;
+        events.push(event);
+
+        Ok(id)
+    }
+
+    /// Removes the scheduled event with the given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `id` - The ID of the event.
+    ///
+    /// # Returns
+    ///
+    /// The removed event if it exists, otherwise `None`.
+    pub fn remove_event(&mut self, chat_id: &ChatId, id: u32) -> Option<ScheduledEvent> {
+        let events = self.events.get_mut(chat_id)?;
+        let idx = events.iter().position(|event| event.id == id)?;
+        let event = events.remove(idx);
+        if events.is_empty() {
+            self.events.remove(chat_id);
+        }
+        Some(event)
+    }
+
+    /// Removes the one-off events which will never occur after the given date in all chats.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The current date.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed events.
+    pub fn remove_finished_events(&mut self, today: NaiveDate) -> usize {
+        let mut removed = 0;
+        for events in self.events.values_mut() {
+            let len = events.len();
+            events.retain(|event| !event.recurrence.is_finished(today));
+            removed += len - events.len();
+        }
+        self.events.retain(|_, events| !events.is_empty());
+        removed
+    }
+
+    /// Returns the celebration polls for the given chat ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the polls, empty if there are none.
+    pub fn polls(&self, chat_id: &ChatId) -> &[CelebrationPoll] {
+        self.polls
+            .get(chat_id)
+            .map_or(&[], |polls| polls.as_slice())
+    }
+
+    /// Returns the mutable celebration poll with the given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `id` - The ID of the poll.
+    ///
+    /// # Returns
+    ///
+    /// The poll if it exists, otherwise `None`.
+    pub fn poll_mut(&mut self, chat_id: &ChatId, id: u32) -> Option<&mut CelebrationPoll> {
+        self.polls
+            .get_mut(chat_id)?
+            .iter_mut()
+            .find(|poll| poll.id == id)
+    }
+
+    /// Returns the mutable celebration poll posted in Telegram with the given poll ID in any chat.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll_id` - The Telegram ID of the poll.
+    ///
+    /// # Returns
+    ///
+    /// The poll if it is tracked, otherwise `None`.
+    pub fn poll_by_telegram_id_mut(&mut self, poll_id: &str) -> Option<&mut CelebrationPoll> {
+        self.polls
+            .values_mut()
+            .flatten()
+            .find(|poll| poll.poll_id.as_deref() == Some(poll_id))
+    }
+
+    /// Adds the celebration poll for the given chat ID and assigns it a unique ID.
+    /// If amount of memory used by the map exceeds the limit, an error will be returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `poll` - The new poll.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with the ID of the poll, or an error if the map is full.
+    pub fn add_poll(&mut self, chat_id: &ChatId, mut poll: CelebrationPoll) -> Result<u32, Error> {
+        if self.estimate_size() + std::mem::size_of_val(chat_id) + std::mem::size_of_val(&poll)
+            > BIRTHDAY_MAP_LIMIT
+        {
+            return Err(Error::new(ErrorKind::BirthdayMapFull));
+        }
+
+        let polls = self.polls.entry(*chat_id).or_default();
+        poll.id = polls.iter().map(|poll| poll.id).max().unwrap_or_default() + 1;
+        let id = poll.id;
+        polls.push(poll);
+
+        Ok(id)
+    }
+
+    /// Removes the celebration poll with the given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `chat_id` - The chat ID.
+    /// * `id` - The ID of the poll.
+    ///
+    /// # Returns
+    ///
+    /// The removed poll if it exists, otherwise `None`.
+    pub fn remove_poll(&mut self, chat_id: &ChatId, id: u32) -> Option<CelebrationPoll> {
+        let polls = self.polls.get_mut(chat_id)?;
+        let idx = polls.iter().position(|poll| poll.id == id)?;
+        let poll = polls.remove(idx);
+        if polls.is_empty() {
+            self.polls.remove(chat_id);
+        }
+        Some(poll)
+    }
+
     /// Function returns the size of the map in bytes.
     ///
     /// # Returns
@@ -253,19 +665,193 @@ impl BirthdaysMap {
             size += std::mem::size_of_val(state);
             size += std::mem::size_of_val(birthdays);
         }
+        for (chat_id, settings) in self.settings.iter() {
+            size += std::mem::size_of_val(chat_id);
+            size += std::mem::size_of_val(settings);
+        }
+        for (chat_id, collections) in self.collections.iter() {
+            size += std::mem::size_of_val(chat_id);
+            size += std::mem::size_of_val(collections.as_slice());
+        }
+        for (user_id, wishlist) in self.wishlists.iter() {
+            size += std::mem::size_of_val(user_id);
+            size += std::mem::size_of_val(wishlist.as_slice());
+        }
+        for (chat_id, events) in self.events.iter() {
+            size += std::mem::size_of_val(chat_id);
+            size += std::mem::size_of_val(events.as_slice());
+        }
+        for (chat_id, polls) in self.polls.iter() {
+            size += std::mem::size_of_val(chat_id);
+            size += std::mem::size_of_val(polls.as_slice());
+        }
         size
     }
 }
 
-/// Represents a birthday with a name, date, and username.
+/// Represents a birthday or another yearly event with a name, date, and username.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct Birthday {
     /// The name of the person.
     pub name: String,
-    /// The date of the birthday.
+    /// The date of the event.
     pub date: String,
     /// The username of the person.
     pub username: String,
+    /// The year of birth of the person, or the start year of another event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<i32>,
+    /// The Telegram user ID of the person.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    /// The kind of the event, omitted in JSON for birthdays.
+    #[serde(default, skip_serializing_if = "EventKind::is_birthday")]
+    pub kind: EventKind,
+    /// The tags of the person, e.g. "backend" or "family", used to filter the list and to
+    /// configure the delivery of the reminders.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The notes about the person visible only to the administrators, e.g. "allergic to nuts".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    /// The history of the gifts given to the person, visible only to the administrators.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gifts: Vec<Gift>,
+}
+
+/// Represents a gift given to the person in a year.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Gift {
+    /// The year the gift was given in.
+    pub year: i32,
+    /// The description of the gift, e.g. "a book".
+    pub description: String,
+}
+
+impl Birthday {
+    /// Returns the day and month of the birthday.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of day and month if the date is in the `DD-MM` format, otherwise `None`.
+    pub fn day_month(&self) -> Option<(u32, u32)> {
+        let (day, month) = self.date.split_once('-')?;
+        let day = day.parse::<u32>().ok()?;
+        let month = month.parse::<u32>().ok()?;
+
+        // 2000 is a leap year, so the 29th of February is accepted as well.
+        NaiveDate::from_ymd_opt(2000, month, day).map(|_| (day, month))
+    }
+
+    /// Returns the date of the next occurrence of the birthday, including the given date itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The date to start searching from.
+    ///
+    /// # Returns
+    ///
+    /// The date of the next birthday if the date of the birthday is valid, otherwise `None`.
+    pub fn next_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let (day, month) = self.day_month()?;
+
+        // The 29th of February may be up to eight years away.
+        (today.year()..=today.year() + 8)
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+            .find(|date| *date >= today)
+    }
+
+    /// Returns the HTML mention of the person.
+    /// If the birthday is linked to a Telegram user, the mention notifies the person even without
+    /// a username, otherwise the stored username is used.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the mention formatted for the HTML parse mode.
+    pub fn mention(&self) -> String {
+        match self.user_id {
+            Some(user_id) => html::user_mention(user_id.0 as i64, &self.name),
+            None if !self.username.is_empty() => format!(
+                "{} ({})",
+                html::escape(&self.name),
+                html::escape(&self.username)
+            ),
+            None => html::escape(&self.name),
+        }
+    }
+
+    /// Returns the name of the kind of the event in parentheses, or an empty string for birthdays.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the name.
+    ///
+    /// # Returns
+    ///
+    /// A `String` to append to the name of the person, e.g. " (work anniversary)".
+    pub fn kind_label(&self, lang: Language) -> String {
+        if self.kind.is_birthday() {
+            String::new()
+        } else {
+            format!(" ({})", self.kind.name(lang))
+        }
+    }
+
+    /// Returns whether the birthday has the given tag.
+    /// The tags are compared case-insensitively and the leading "#" is ignored, so the tags
+    /// uploaded in JSON files match the tags typed in commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to look for, with or without the leading "#".
+    ///
+    /// # Returns
+    ///
+    /// `true` if the birthday has the tag, otherwise `false`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = crate::utils::normalize_text(tag.trim_start_matches('#'));
+        self.tags
+            .iter()
+            .any(|own| crate::utils::normalize_text(own.trim_start_matches('#')) == tag)
+    }
+
+    /// Returns whether the birthday has all the given tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `tags` - The tags to look for, empty to match any birthday.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the birthday has every tag, otherwise `false`.
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|tag| self.has_tag(tag))
+    }
+
+    /// Returns the tags of the birthday as hashtags, or an empty string if there are no tags.
+    ///
+    /// # Returns
+    ///
+    /// A `String` to append to the description of the birthday, e.g. " #backend #family".
+    pub fn tags_label(&self) -> String {
+        self.tags
+            .iter()
+            .map(|tag| format!(" #{}", tag.trim_start_matches('#')))
+            .collect()
+    }
+
+    /// Returns the number of days left until the next birthday.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The date to count from.
+    ///
+    /// # Returns
+    ///
+    /// The number of days left if the date of the birthday is valid, otherwise `None`.
+    pub fn days_until(&self, today: NaiveDate) -> Option<i64> {
+        self.next_date(today).map(|date| (date - today).num_days())
+    }
 }
 
 /// Represents a list of birthdays.
@@ -332,20 +918,159 @@ impl Birthdays {
         }
     }
 
-    /// Returns a string representation of the list of birthdays.
+    /// Returns the mutable reference to the birthday at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the birthday.
+    ///
+    /// # Returns
+    ///
+    /// Some mutable reference to the birthday if it exists, otherwise None.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Birthday> {
+        self.birthdays.get_mut(index)
+    }
+
+    /// Returns the index of the birthday linked to the given Telegram user ID.
+    /// Other events of the person are not taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The Telegram user ID.
     ///
     /// # Returns
     ///
+    /// Some index of the birthday if it exists, otherwise None.
+    pub fn position_by_user(&self, user_id: UserId) -> Option<usize> {
+        self.birthdays
+            .iter()
+            .position(|birthday| birthday.kind.is_birthday() && birthday.user_id == Some(user_id))
+    }
+
+    /// Returns the upcoming birthdays sorted by the number of days left.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The date to count from.
+    /// * `count` - The maximum number of birthdays to return.
+    /// * `tags` - The tags the birthdays must all have, empty to return all birthdays.
+    ///
+    /// # Returns
+    ///
+    /// A list of birthdays paired with the number of days left until each of them.
+    pub fn upcoming(
+        &self,
+        today: NaiveDate,
+        count: usize,
+        tags: &[String],
+    ) -> Vec<(&Birthday, i64)> {
+        let mut upcoming = self
+            .birthdays
+            .iter()
+            .filter(|birthday| birthday.has_tags(tags))
+            .filter_map(|birthday| birthday.days_until(today).map(|days| (birthday, days)))
+            .collect::<Vec<_>>();
+        upcoming.sort_by_key(|(_, days)| *days);
+        upcoming.truncate(count);
+        upcoming
+    }
+
+    /// Returns the birthdays celebrated on the given date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to look for.
+    ///
+    /// # Returns
+    ///
+    /// A list of birthdays celebrated on the given date.
+    pub fn on_date(&self, date: NaiveDate) -> Vec<&Birthday> {
+        self.birthdays
+            .iter()
+            .filter(|birthday| birthday.day_month() == Some((date.day(), date.month())))
+            .collect()
+    }
+
+    /// Returns the birthdays celebrated in the given month sorted by day.
+    ///
+    /// # Arguments
+    ///
+    /// * `month` - The month number starting from 1.
+    ///
+    /// # Returns
+    ///
+    /// A list of birthdays celebrated in the given month.
+    pub fn in_month(&self, month: u32) -> Vec<&Birthday> {
+        let mut in_month = self
+            .birthdays
+            .iter()
+            .filter(|birthday| matches!(birthday.day_month(), Some((_, m)) if m == month))
+            .collect::<Vec<_>>();
+        in_month.sort_by_key(|birthday| birthday.day_month());
+        in_month
+    }
+
+    /// Returns the birthdays whose name or username contains the given query.
+    /// The search is case-insensitive and treats "ё" and "е" as the same letter.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text to search for.
+    ///
+    /// # Returns
+    ///
+    /// A list of matching birthdays paired with their indexes in the list.
+    pub fn find(&self, query: &str) -> Vec<(usize, &Birthday)> {
+        let query = crate::utils::normalize_text(query);
+        let username_query = query.trim_start_matches('@');
+
+        self.birthdays
+            .iter()
+            .enumerate()
+            .filter(|(_, birthday)| {
+                crate::utils::normalize_text(&birthday.name).contains(&query)
+                    || (!username_query.is_empty()
+                        && crate::utils::normalize_text(&birthday.username)
+                            .contains(username_query))
+            })
+            .collect()
+    }
+
+    /// Returns a string representation of the list of birthdays.
+    /// The numbers in the list are the indexes of the events, so they stay the same when the list
+    /// is filtered by the kind of the event or by the tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the list.
+    /// * `kind` - The kind of the events to show, or None to show all events.
+    /// * `tags` - The tags the events must all have, empty to show all events.
+    ///
+    /// # Returns
     ///
-    pub fn list(&self) -> String {
-        if self.birthdays.is_empty() {
-            "Список дней рождений пуст".to_string()
+    /// A `String` with the numbered list of birthdays.
+    pub fn list(&self, lang: Language, kind: Option<EventKind>, tags: &[String]) -> String {
+        let tr = lang.messages();
+        let birthdays = self
+            .birthdays
+            .iter()
+            .enumerate()
+            .filter(|(_, birthday)| kind.is_none() || kind == Some(birthday.kind))
+            .filter(|(_, birthday)| birthday.has_tags(tags))
+            .collect::<Vec<_>>();
+        if birthdays.is_empty() {
+            tr.list_empty.to_string()
         } else {
-            let mut reply_text = String::from("Список дней рождений:\n");
-            for (idx, birthday) in self.birthdays.iter().enumerate() {
+            let mut reply_text = format!("{}\n", tr.list_header);
+            for (idx, birthday) in birthdays {
                 reply_text += format!(
-                    "{}. {} - {} {}\n",
-                    idx, birthday.name, birthday.date, birthday.username
+                    "{}. {}{} - {} {}{}\n",
+                    idx,
+                    birthday.name,
+                    birthday.kind_label(lang),
+                    birthday.date,
+                    birthday.username,
+                    birthday.tags_label()
                 )
                 .as_str();
             }
@@ -0,0 +1,193 @@
+use chrono::{Datelike, NaiveDate};
+use image::{Rgb, RgbImage};
+
+use crate::card::{self, CardError};
+use crate::{Birthday, Language};
+
+/// The margin between the calendar and the edges of the image in pixels.
+const MARGIN: u32 = 40;
+
+/// The width of the cell of a day in pixels.
+const CELL_WIDTH: u32 = 200;
+
+/// The height of the cell of a day in pixels.
+const CELL_HEIGHT: u32 = 160;
+
+/// The height of the title with the month and the year in pixels.
+const TITLE_HEIGHT: u32 = 110;
+
+/// The height of the row with the names of the weekdays in pixels.
+const WEEKDAYS_HEIGHT: u32 = 56;
+
+/// The maximum number of names shown in the cell of a day.
+const NAMES_PER_DAY: usize = 3;
+
+/// The colors of the calendar.
+const BACKGROUND_COLOR: Rgb<u8> = Rgb([250, 246, 240]);
+const CELL_COLOR: Rgb<u8> = Rgb([255, 255, 255]);
+const WEEKEND_COLOR: Rgb<u8> = Rgb([243, 239, 250]);
+const BIRTHDAY_COLOR: Rgb<u8> = Rgb([255, 226, 190]);
+const TEXT_COLOR: Rgb<u8> = Rgb([60, 50, 70]);
+const MUTED_COLOR: Rgb<u8> = Rgb([130, 120, 140]);
+const ACCENT_COLOR: Rgb<u8> = Rgb([229, 57, 53]);
+
+/// Returns the short form of the name fitting in the cell of a day, e.g. "Иван П." for
+/// "Иван Петров".
+fn short_name(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    match (
+        words.next(),
+        words.next().and_then(|word| word.chars().next()),
+    ) {
+        (Some(first), Some(initial)) => format!("{} {}.", first, initial),
+        (Some(first), None) => first.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Returns the number of days in the month of the date.
+fn days_in_month(date: NaiveDate) -> u32 {
+    (28..=31)
+        .rev()
+        .find(|day| date.with_day(*day).is_some())
+        .unwrap_or(28)
+}
+
+/// Draws the outline of the rectangle of the given thickness.
+fn draw_outline(image: &mut RgbImage, x: i32, y: i32, width: i32, height: i32, color: Rgb<u8>) {
+    const THICKNESS: i32 = 4;
+    card::fill_rect(image, x, y, width, THICKNESS, color);
+    card::fill_rect(image, x, y + height - THICKNESS, width, THICKNESS, color);
+    card::fill_rect(image, x, y, THICKNESS, height, color);
+    card::fill_rect(image, x + width - THICKNESS, y, THICKNESS, height, color);
+}
+
+/// Renders the calendar of the month with the names of the people on their birthdays.
+/// The weeks start on Monday, the weekends and the days with birthdays are highlighted, and
+/// today is outlined if it falls in the month.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays celebrated in the month.
+/// * `month` - The first day of the month to render.
+/// * `today` - The current date.
+/// * `lang` - The language of the names of the month and the weekdays.
+///
+/// # Returns
+///
+/// The PNG image of the calendar, or a `CardError` if it could not be rendered.
+pub fn render(
+    birthdays: &[&Birthday],
+    month: NaiveDate,
+    today: NaiveDate,
+    lang: Language,
+) -> Result<Vec<u8>, CardError> {
+    let tr = lang.messages();
+    let font = card::sans_font()?;
+
+    let days = days_in_month(month);
+    let offset = month.weekday().num_days_from_monday();
+    let weeks = (offset + days).div_ceil(7);
+    let width = 2 * MARGIN + 7 * CELL_WIDTH;
+    let height = 2 * MARGIN + TITLE_HEIGHT + WEEKDAYS_HEIGHT + weeks * CELL_HEIGHT;
+    let mut image = RgbImage::from_pixel(width, height, BACKGROUND_COLOR);
+
+    let title = format!(
+        "{} {}",
+        tr.month_names[month.month0() as usize],
+        month.year()
+    );
+    let center_y = (MARGIN + TITLE_HEIGHT / 2) as f32;
+    let span = (MARGIN as f32, (width - MARGIN) as f32);
+    card::draw_text(&mut image, &font, 64.0, span, center_y, &title, TEXT_COLOR);
+
+    let center_y = (MARGIN + TITLE_HEIGHT + WEEKDAYS_HEIGHT / 2) as f32;
+    for (column, weekday) in tr.weekdays_short.iter().enumerate() {
+        let left = (MARGIN + column as u32 * CELL_WIDTH) as f32;
+        let color = if column >= 5 {
+            ACCENT_COLOR
+        } else {
+            MUTED_COLOR
+        };
+        let span = (left, left + CELL_WIDTH as f32);
+        card::draw_text(&mut image, &font, 28.0, span, center_y, weekday, color);
+    }
+
+    let top = MARGIN + TITLE_HEIGHT + WEEKDAYS_HEIGHT;
+    for day in 1..=days {
+        let index = offset + day - 1;
+        let (column, row) = (index % 7, index / 7);
+        let x = (MARGIN + column * CELL_WIDTH) as i32;
+        let y = (top + row * CELL_HEIGHT) as i32;
+        let (cell_width, cell_height) = (CELL_WIDTH as i32, CELL_HEIGHT as i32);
+
+        let people = birthdays
+            .iter()
+            .filter(|birthday| matches!(birthday.day_month(), Some((d, _)) if d == day))
+            .collect::<Vec<_>>();
+        let color = if !people.is_empty() {
+            BIRTHDAY_COLOR
+        } else if column >= 5 {
+            WEEKEND_COLOR
+        } else {
+            CELL_COLOR
+        };
+        card::fill_rect(
+            &mut image,
+            x + 3,
+            y + 3,
+            cell_width - 6,
+            cell_height - 6,
+            color,
+        );
+        if month.with_day(day) == Some(today) {
+            draw_outline(
+                &mut image,
+                x + 3,
+                y + 3,
+                cell_width - 6,
+                cell_height - 6,
+                ACCENT_COLOR,
+            );
+        }
+
+        let color = if people.is_empty() {
+            MUTED_COLOR
+        } else {
+            ACCENT_COLOR
+        };
+        let span = (x as f32 + 8.0, x as f32 + 56.0);
+        let center_y = y as f32 + 28.0;
+        card::draw_text(
+            &mut image,
+            &font,
+            30.0,
+            span,
+            center_y,
+            &day.to_string(),
+            color,
+        );
+
+        // If there are too many people, the last line shows how many are not shown.
+        let shown = if people.len() > NAMES_PER_DAY {
+            NAMES_PER_DAY - 1
+        } else {
+            people.len()
+        };
+        let mut lines = people
+            .iter()
+            .take(shown)
+            .map(|birthday| short_name(&birthday.name))
+            .collect::<Vec<_>>();
+        if shown < people.len() {
+            lines.push(format!("+{}", people.len() - shown));
+        }
+        let span = (x as f32 + 10.0, (x + cell_width) as f32 - 10.0);
+        for (line, text) in lines.iter().enumerate() {
+            let center_y = y as f32 + 64.0 + line as f32 * 32.0;
+            card::draw_text(&mut image, &font, 24.0, span, center_y, text, TEXT_COLOR);
+        }
+    }
+
+    card::encode_png(&image)
+}
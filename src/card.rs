@@ -0,0 +1,504 @@
+use std::fmt;
+
+use ab_glyph::{point, Font, FontRef, InvalidFont, PxScale, ScaleFont};
+use chrono::{Datelike, NaiveDate};
+use image::codecs::png::PngEncoder;
+use image::{ColorType, ImageEncoder, ImageError, Rgb, RgbImage};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinError;
+
+use crate::i18n::fill;
+use crate::{Birthday, Language};
+
+/// The width of the card in pixels.
+const CARD_WIDTH: u32 = 1200;
+
+/// The height of the card in pixels.
+const CARD_HEIGHT: u32 = 800;
+
+/// The margin between the text and the edges of the card in pixels.
+const CARD_MARGIN: f32 = 120.0;
+
+/// The number of decorations drawn on the card.
+const DECORATIONS: usize = 90;
+
+/// The sans-serif font bundled with the binary.
+const SANS_FONT: &[u8] = include_bytes!("../assets/fonts/DejaVuSans-Bold.ttf");
+
+/// The serif font bundled with the binary.
+const SERIF_FONT: &[u8] = include_bytes!("../assets/fonts/DejaVuSerif-Bold.ttf");
+
+/// Represents the background theme of the greeting cards.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardTheme {
+    /// A warm gradient with colorful confetti.
+    #[default]
+    Confetti,
+    /// A night sky with stars.
+    Night,
+    /// Soft pastel colors with balloons.
+    Pastel,
+    /// A cream background with a golden frame.
+    Classic,
+}
+
+impl CardTheme {
+    /// All themes in the order they are shown to users.
+    pub const ALL: [CardTheme; 4] = [
+        CardTheme::Confetti,
+        CardTheme::Night,
+        CardTheme::Pastel,
+        CardTheme::Classic,
+    ];
+
+    /// Returns the English word used to refer to the theme in commands, e.g. "night".
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            CardTheme::Confetti => "confetti",
+            CardTheme::Night => "night",
+            CardTheme::Pastel => "pastel",
+            CardTheme::Classic => "classic",
+        }
+    }
+
+    /// Parses the theme typed by an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The name of the theme in English or Russian, e.g. "night" or "ночь".
+    ///
+    /// # Returns
+    ///
+    /// Some theme if the input is recognized, otherwise None.
+    pub fn parse(input: &str) -> Option<CardTheme> {
+        match crate::utils::normalize_text(input).as_str() {
+            "confetti" | "конфетти" => Some(CardTheme::Confetti),
+            "night" | "ночь" => Some(CardTheme::Night),
+            "pastel" | "пастель" => Some(CardTheme::Pastel),
+            "classic" | "классика" => Some(CardTheme::Classic),
+            _ => None,
+        }
+    }
+
+    /// Returns the description of the theme in the given language.
+    pub fn describe(&self, lang: Language) -> &'static str {
+        let tr = lang.messages();
+        match self {
+            CardTheme::Confetti => tr.card_theme_confetti,
+            CardTheme::Night => tr.card_theme_night,
+            CardTheme::Pastel => tr.card_theme_pastel,
+            CardTheme::Classic => tr.card_theme_classic,
+        }
+    }
+
+    /// Returns the colors of the top and the bottom of the background gradient.
+    fn background(&self) -> (Rgb<u8>, Rgb<u8>) {
+        match self {
+            CardTheme::Confetti => (Rgb([255, 214, 102]), Rgb([255, 138, 101])),
+            CardTheme::Night => (Rgb([20, 24, 66]), Rgb([74, 40, 120])),
+            CardTheme::Pastel => (Rgb([255, 228, 240]), Rgb([214, 232, 255])),
+            CardTheme::Classic => (Rgb([252, 247, 232]), Rgb([236, 224, 196])),
+        }
+    }
+
+    /// Returns the color of the text.
+    fn text_color(&self) -> Rgb<u8> {
+        match self {
+            CardTheme::Confetti => Rgb([74, 28, 44]),
+            CardTheme::Night => Rgb([255, 248, 225]),
+            CardTheme::Pastel => Rgb([84, 70, 120]),
+            CardTheme::Classic => Rgb([110, 72, 20]),
+        }
+    }
+
+    /// Returns the colors of the decorations.
+    fn palette(&self) -> &'static [Rgb<u8>] {
+        match self {
+            CardTheme::Confetti => &[
+                Rgb([229, 57, 53]),
+                Rgb([30, 136, 229]),
+                Rgb([67, 160, 71]),
+                Rgb([142, 36, 170]),
+                Rgb([255, 255, 255]),
+            ],
+            CardTheme::Night => &[Rgb([255, 255, 255]), Rgb([255, 236, 179])],
+            CardTheme::Pastel => &[
+                Rgb([244, 143, 177]),
+                Rgb([129, 212, 250]),
+                Rgb([197, 225, 165]),
+                Rgb([206, 147, 216]),
+            ],
+            CardTheme::Classic => &[Rgb([191, 144, 0])],
+        }
+    }
+
+    /// Returns the font of the theme.
+    fn font(&self) -> &'static [u8] {
+        match self {
+            CardTheme::Confetti | CardTheme::Night => SANS_FONT,
+            CardTheme::Pastel | CardTheme::Classic => SERIF_FONT,
+        }
+    }
+}
+
+/// Represents an error that can occur when rendering a greeting card or another image.
+#[derive(Debug)]
+pub enum CardError {
+    /// The bundled font could not be loaded.
+    Font(InvalidFont),
+    /// The image could not be encoded.
+    Image(ImageError),
+    /// The rendering task panicked or was cancelled.
+    Task(JoinError),
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardError::Font(e) => write!(f, "failed to load the font: {}", e),
+            CardError::Image(e) => write!(f, "failed to encode the image: {}", e),
+            CardError::Task(e) => write!(f, "the rendering task failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CardError {}
+
+/// A simple pseudo-random generator, so the card of a person looks the same every time.
+struct Scatter(u64);
+
+impl Scatter {
+    /// Creates a generator seeded with the text.
+    fn new(seed: &str) -> Self {
+        // FNV-1a hash of the text, never zero.
+        let hash = seed.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+        Self(hash | 1)
+    }
+
+    /// Returns the next number in the range from 0 to `max`, excluding `max`.
+    fn next(&mut self, max: u32) -> u32 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % max.max(1) as u64) as u32
+    }
+}
+
+/// Blends the color into the pixel of the image, ignoring the coordinates outside of the image.
+fn blend(image: &mut RgbImage, x: i32, y: i32, color: Rgb<u8>, alpha: f32) {
+    if x < 0 || y < 0 || x >= image.width() as i32 || y >= image.height() as i32 {
+        return;
+    }
+    let alpha = alpha.clamp(0.0, 1.0);
+    let pixel = image.get_pixel_mut(x as u32, y as u32);
+    for (channel, value) in pixel.0.iter_mut().zip(color.0) {
+        *channel = (*channel as f32 * (1.0 - alpha) + value as f32 * alpha).round() as u8;
+    }
+}
+
+/// Fills the circle with the color.
+fn fill_circle(image: &mut RgbImage, cx: i32, cy: i32, radius: i32, color: Rgb<u8>, alpha: f32) {
+    for y in -radius..=radius {
+        for x in -radius..=radius {
+            if x * x + y * y <= radius * radius {
+                blend(image, cx + x, cy + y, color, alpha);
+            }
+        }
+    }
+}
+
+/// Fills the rectangle with the color.
+pub fn fill_rect(image: &mut RgbImage, x: i32, y: i32, width: i32, height: i32, color: Rgb<u8>) {
+    for dy in 0..height {
+        for dx in 0..width {
+            blend(image, x + dx, y + dy, color, 1.0);
+        }
+    }
+}
+
+/// Draws the frame of the given thickness inset from the edges of the image.
+fn draw_frame(image: &mut RgbImage, inset: i32, thickness: i32, color: Rgb<u8>) {
+    let (width, height) = (image.width() as i32, image.height() as i32);
+    let inner_width = width - 2 * inset;
+    let inner_height = height - 2 * inset;
+    fill_rect(image, inset, inset, inner_width, thickness, color);
+    fill_rect(
+        image,
+        inset,
+        height - inset - thickness,
+        inner_width,
+        thickness,
+        color,
+    );
+    fill_rect(image, inset, inset, thickness, inner_height, color);
+    fill_rect(
+        image,
+        width - inset - thickness,
+        inset,
+        thickness,
+        inner_height,
+        color,
+    );
+}
+
+/// Paints the background gradient and the decorations of the theme.
+fn draw_background(image: &mut RgbImage, theme: CardTheme, seed: &str) {
+    let (top, bottom) = theme.background();
+    let height = image.height();
+    for (_, y, pixel) in image.enumerate_pixels_mut() {
+        let t = y as f32 / height as f32;
+        for ((channel, from), to) in pixel.0.iter_mut().zip(top.0).zip(bottom.0) {
+            *channel = (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+        }
+    }
+
+    if theme == CardTheme::Classic {
+        let color = theme.palette()[0];
+        draw_frame(image, 30, 8, color);
+        draw_frame(image, 50, 3, color);
+        return;
+    }
+
+    // The decorations are scattered around the text, leaving the middle of the card clear.
+    let mut scatter = Scatter::new(seed);
+    let palette = theme.palette();
+    let mut drawn = 0;
+    while drawn < DECORATIONS {
+        let x = scatter.next(CARD_WIDTH) as i32;
+        let y = scatter.next(CARD_HEIGHT) as i32;
+        let color = palette[scatter.next(palette.len() as u32) as usize];
+        let in_middle = (CARD_WIDTH as i32 / 10..CARD_WIDTH as i32 * 9 / 10).contains(&x)
+            && (CARD_HEIGHT as i32 / 5..CARD_HEIGHT as i32 * 17 / 20).contains(&y);
+        if in_middle {
+            continue;
+        }
+        match theme {
+            CardTheme::Confetti => {
+                if scatter.next(2) == 0 {
+                    fill_circle(image, x, y, 6 + scatter.next(6) as i32, color, 0.9);
+                } else {
+                    let (width, height) =
+                        (8 + scatter.next(8) as i32, 16 + scatter.next(12) as i32);
+                    fill_rect(image, x, y, width, height, color);
+                }
+            }
+            CardTheme::Night => {
+                let radius = 1 + scatter.next(4) as i32;
+                fill_circle(
+                    image,
+                    x,
+                    y,
+                    radius,
+                    color,
+                    0.5 + scatter.next(50) as f32 / 100.0,
+                );
+                if radius > 3 {
+                    // A twinkle on the brightest stars.
+                    fill_rect(image, x - 12, y, 25, 1, color);
+                    fill_rect(image, x, y - 12, 1, 25, color);
+                }
+            }
+            CardTheme::Pastel => {
+                // Fewer but bigger balloons on strings.
+                if drawn % 4 == 0 {
+                    let radius = 30 + scatter.next(25) as i32;
+                    fill_rect(image, x, y + radius, 2, radius, theme.text_color());
+                    fill_circle(image, x, y, radius, color, 0.85);
+                }
+            }
+            CardTheme::Classic => {}
+        }
+        drawn += 1;
+    }
+}
+
+/// Returns the width of the text rendered with the font of the given size.
+fn text_width(font: &FontRef<'static>, scale: PxScale, text: &str) -> f32 {
+    let font = font.as_scaled(scale);
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = font.glyph_id(c);
+        if let Some(previous) = previous {
+            width += font.kern(previous, id);
+        }
+        width += font.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+/// Runs the rendering of an image on the threads for blocking work, so the pixel work and the
+/// encoding don't stall the async executor.
+///
+/// # Arguments
+///
+/// * `render` - The function rendering the image.
+///
+/// # Returns
+///
+/// The PNG image, or a `CardError` if it could not be rendered.
+pub async fn render_blocking<F>(render: F) -> Result<Vec<u8>, CardError>
+where
+    F: FnOnce() -> Result<Vec<u8>, CardError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(render)
+        .await
+        .map_err(CardError::Task)?
+}
+
+/// Loads the bundled sans-serif font.
+pub fn sans_font() -> Result<FontRef<'static>, CardError> {
+    FontRef::try_from_slice(SANS_FONT).map_err(CardError::Font)
+}
+
+/// Draws the line of text centered horizontally between the left and the right edges and
+/// vertically around the given position. The text is scaled down if it doesn't fit between the
+/// edges.
+///
+/// # Arguments
+///
+/// * `image` - The image to draw on.
+/// * `font` - The font of the text.
+/// * `size` - The preferred size of the font in pixels.
+/// * `(left, right)` - The horizontal positions of the left and the right edges.
+/// * `center_y` - The vertical position of the middle of the text.
+/// * `text` - The text to draw.
+/// * `color` - The color of the text.
+pub fn draw_text(
+    image: &mut RgbImage,
+    font: &FontRef<'static>,
+    size: f32,
+    (left, right): (f32, f32),
+    center_y: f32,
+    text: &str,
+    color: Rgb<u8>,
+) {
+    let max_width = right - left;
+    let width = text_width(font, PxScale::from(size), text);
+    let scale = if width > max_width {
+        PxScale::from(size * max_width / width)
+    } else {
+        PxScale::from(size)
+    };
+    let scaled = font.as_scaled(scale);
+
+    let mut x = left + (max_width - text_width(font, scale, text)) / 2.0;
+    let baseline = center_y + (scaled.ascent() + scaled.descent()) / 2.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(previous) = previous {
+            x += scaled.kern(previous, id);
+        }
+        let glyph = id.with_scale_and_position(scale, point(x, baseline));
+        if let Some(outlined) = font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+            outlined.draw(|gx, gy, coverage| {
+                blend(
+                    image,
+                    bounds.min.x as i32 + gx as i32,
+                    bounds.min.y as i32 + gy as i32,
+                    color,
+                    coverage,
+                );
+            });
+        }
+        x += scaled.h_advance(id);
+        previous = Some(id);
+    }
+}
+
+/// Renders the greeting card with the name and the age of the person.
+///
+/// # Arguments
+///
+/// * `theme` - The background theme of the card.
+/// * `name` - The name of the person.
+/// * `age` - The age the person turns, or None if the year of birth is unknown.
+/// * `lang` - The language of the greeting on the card.
+///
+/// # Returns
+///
+/// The PNG image of the card, or a `CardError` if it could not be rendered.
+pub fn render(
+    theme: CardTheme,
+    name: &str,
+    age: Option<i32>,
+    lang: Language,
+) -> Result<Vec<u8>, CardError> {
+    let tr = lang.messages();
+    let font = FontRef::try_from_slice(theme.font()).map_err(CardError::Font)?;
+    let color = theme.text_color();
+    let height = CARD_HEIGHT as f32;
+    let span = (CARD_MARGIN, CARD_WIDTH as f32 - CARD_MARGIN);
+
+    let mut image = RgbImage::new(CARD_WIDTH, CARD_HEIGHT);
+    draw_background(&mut image, theme, name);
+    draw_text(
+        &mut image,
+        &font,
+        72.0,
+        span,
+        height * 0.3,
+        tr.card_title,
+        color,
+    );
+    draw_text(&mut image, &font, 110.0, span, height * 0.5, name, color);
+    if let Some(age) = age.filter(|age| *age > 0) {
+        let years = format!("{} {}", age, lang.plural(age as i64, &tr.years));
+        let text = fill(tr.card_age, &[("age", &age), ("years", &years)]);
+        draw_text(&mut image, &font, 60.0, span, height * 0.7, &text, color);
+    }
+
+    encode_png(&image)
+}
+
+/// Encodes the image in the PNG format.
+///
+/// # Arguments
+///
+/// * `image` - The image to encode.
+///
+/// # Returns
+///
+/// The PNG image, or a `CardError` if it could not be encoded.
+pub fn encode_png(image: &RgbImage) -> Result<Vec<u8>, CardError> {
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(
+            image.as_raw(),
+            image.width(),
+            image.height(),
+            ColorType::Rgb8,
+        )
+        .map_err(CardError::Image)?;
+    Ok(png)
+}
+
+/// Renders the greeting card for the next birthday of the person.
+///
+/// # Arguments
+///
+/// * `theme` - The background theme of the card.
+/// * `birthday` - The birthday of the person.
+/// * `today` - The current date.
+/// * `lang` - The language of the greeting on the card.
+///
+/// # Returns
+///
+/// The PNG image of the card, or a `CardError` if it could not be rendered.
+pub fn render_birthday(
+    theme: CardTheme,
+    birthday: &Birthday,
+    today: NaiveDate,
+    lang: Language,
+) -> Result<Vec<u8>, CardError> {
+    let date = birthday.next_date(today).unwrap_or(today);
+    let age = birthday.year.map(|year| date.year() - year);
+    render(theme, &birthday.name, age, lang)
+}
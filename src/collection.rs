@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, MessageId, User, UserId};
+
+use crate::i18n::fill;
+use crate::Language;
+
+/// The prefix of the callback data of the collection buttons.
+const CALLBACK_PREFIX: &str = "collection";
+
+/// Represents the action of the collection button.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CollectionAction {
+    /// The member marks or unmarks that they paid.
+    Paid,
+    /// The organizer closes the collection.
+    Close,
+}
+
+impl CollectionAction {
+    /// Returns the name of the action used in the callback data.
+    fn name(&self) -> &'static str {
+        match self {
+            CollectionAction::Paid => "paid",
+            CollectionAction::Close => "close",
+        }
+    }
+
+    /// Returns the callback data of the button with the action for the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the collection.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the callback data, e.g. "collection:paid:1".
+    pub fn callback_data(&self, id: u32) -> String {
+        format!("{}:{}:{}", CALLBACK_PREFIX, self.name(), id)
+    }
+
+    /// Parses the callback data of the collection button.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The callback data.
+    ///
+    /// # Returns
+    ///
+    /// Some action with the ID of the collection if the data belongs to a collection button,
+    /// otherwise None.
+    pub fn parse(data: &str) -> Option<(CollectionAction, u32)> {
+        let mut parts = data.split(':');
+        if parts.next()? != CALLBACK_PREFIX {
+            return None;
+        }
+        let action = match parts.next()? {
+            "paid" => CollectionAction::Paid,
+            "close" => CollectionAction::Close,
+            _ => return None,
+        };
+        let id = parts.next()?.parse::<u32>().ok()?;
+        Some((action, id))
+    }
+}
+
+/// Represents a member who paid for the gift.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Contributor {
+    /// The Telegram user ID of the member.
+    pub user_id: UserId,
+    /// The name of the member.
+    pub name: String,
+}
+
+/// Represents a gift money collection for an upcoming birthday.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Collection {
+    /// The ID of the collection, unique within the chat.
+    pub id: u32,
+    /// The name of the person the gift is for.
+    pub name: String,
+    /// The date of the birthday.
+    pub date: String,
+    /// The target amount of money.
+    pub target: u64,
+    /// The Telegram user ID of the organizer.
+    pub organizer: UserId,
+    /// The name of the organizer.
+    pub organizer_name: String,
+    /// The members who paid.
+    pub contributors: Vec<Contributor>,
+    /// The ID of the pinned message with the collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+}
+
+impl Collection {
+    /// Marks the user as a contributor, or unmarks them if they are already marked.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user who pressed the button.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the user is marked as a contributor, `false` if the mark is removed.
+    pub fn toggle_contributor(&mut self, user: &User) -> bool {
+        if let Some(idx) = self
+            .contributors
+            .iter()
+            .position(|contributor| contributor.user_id == user.id)
+        {
+            self.contributors.remove(idx);
+            false
+        } else {
+            self.contributors.push(Contributor {
+                user_id: user.id,
+                name: user.full_name(),
+            });
+            true
+        }
+    }
+
+    /// Returns the text of the collection message.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the chat.
+    /// * `is_closed` - Whether the collection is closed.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the collection details and the list of contributors.
+    pub fn text(&self, lang: Language, is_closed: bool) -> String {
+        let tr = lang.messages();
+        let contributors = if self.contributors.is_empty() {
+            tr.collection_no_contributors.to_string()
+        } else {
+            self.contributors
+                .iter()
+                .enumerate()
+                .map(|(idx, contributor)| format!("{}. {}\n", idx + 1, contributor.name))
+                .collect::<String>()
+        };
+        let template = if is_closed {
+            tr.collection_closed_text
+        } else {
+            tr.collection_text
+        };
+
+        fill(
+            template,
+            &[
+                ("name", &self.name),
+                ("date", &self.date),
+                ("target", &self.target),
+                ("organizer", &self.organizer_name),
+                ("count", &self.contributors.len()),
+                ("contributors", &contributors),
+            ],
+        )
+    }
+
+    /// Returns the inline keyboard of the collection message.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the chat.
+    ///
+    /// # Returns
+    ///
+    /// An `InlineKeyboardMarkup` with the buttons to mark the payment and to close the collection.
+    pub fn keyboard(&self, lang: Language) -> InlineKeyboardMarkup {
+        let tr = lang.messages();
+        InlineKeyboardMarkup::new(vec![
+            vec![InlineKeyboardButton::callback(
+                tr.collection_paid_button,
+                CollectionAction::Paid.callback_data(self.id),
+            )],
+            vec![InlineKeyboardButton::callback(
+                tr.collection_close_button,
+                CollectionAction::Close.callback_data(self.id),
+            )],
+        ])
+    }
+}
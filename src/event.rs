@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Language;
+
+/// Represents the kind of the event remembered by the bot.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// The birthday of the person, the year is the year of birth.
+    #[default]
+    Birthday,
+    /// The anniversary of joining the company, the year is the year of joining.
+    WorkAnniversary,
+    /// The wedding anniversary, the year is the year of the wedding.
+    Wedding,
+    /// The name day of the person.
+    NameDay,
+    /// The memorial date, the year is the year of the event.
+    Memorial,
+}
+
+impl EventKind {
+    /// All kinds of events in the order they are shown to users.
+    pub const ALL: [EventKind; 5] = [
+        EventKind::Birthday,
+        EventKind::WorkAnniversary,
+        EventKind::Wedding,
+        EventKind::NameDay,
+        EventKind::Memorial,
+    ];
+
+    /// Returns the words users may type to refer to the kind of the event.
+    fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            EventKind::Birthday => &["birthday", "др", "день рождения"],
+            EventKind::WorkAnniversary => &["work", "работа"],
+            EventKind::Wedding => &["wedding", "свадьба"],
+            EventKind::NameDay => &["nameday", "именины"],
+            EventKind::Memorial => &["memorial", "память"],
+        }
+    }
+
+    /// Returns the English word used to refer to the kind of the event in commands, e.g. "work".
+    pub fn keyword(&self) -> &'static str {
+        self.aliases()[0]
+    }
+
+    /// Parses the kind of the event typed by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The name of the kind, e.g. "work" or "свадьба".
+    ///
+    /// # Returns
+    ///
+    /// Some kind of the event if the input is recognized, otherwise None.
+    pub fn parse(input: &str) -> Option<EventKind> {
+        let input = crate::utils::normalize_text(input);
+        EventKind::ALL
+            .into_iter()
+            .find(|kind| kind.aliases().contains(&input.as_str()))
+    }
+
+    /// Returns the list of the words accepted by `parse`, e.g. "birthday, work, wedding".
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the first alias of each kind.
+    pub fn names() -> String {
+        EventKind::ALL
+            .iter()
+            .map(|kind| kind.keyword())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns the name of the kind of the event in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the name.
+    ///
+    /// # Returns
+    ///
+    /// The name of the kind, e.g. "work anniversary".
+    pub fn name(&self, lang: Language) -> &'static str {
+        let tr = lang.messages();
+        match self {
+            EventKind::Birthday => tr.event_birthday,
+            EventKind::WorkAnniversary => tr.event_work_anniversary,
+            EventKind::Wedding => tr.event_wedding,
+            EventKind::NameDay => tr.event_name_day,
+            EventKind::Memorial => tr.event_memorial,
+        }
+    }
+
+    /// Returns the emoji shown next to the events of the kind in lists and digests.
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            EventKind::Birthday => "🎂",
+            EventKind::WorkAnniversary => "💼",
+            EventKind::Wedding => "💍",
+            EventKind::NameDay => "😇",
+            EventKind::Memorial => "🕯",
+        }
+    }
+
+    /// Returns whether the kind is the birthday, used to omit the default kind in JSON.
+    pub fn is_birthday(&self) -> bool {
+        *self == EventKind::Birthday
+    }
+}
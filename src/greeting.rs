@@ -0,0 +1,405 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use rand::seq::SliceRandom;
+use teloxide::utils::html;
+
+use crate::i18n::fill;
+use crate::{Birthday, Birthdays, DeliveryRule, EventKind, Language, Settings};
+
+/// The placeholders which can be used in greeting templates.
+pub const PLACEHOLDERS: [&str; 6] = ["name", "mention", "age", "years", "event", "days_left"];
+
+/// The maximum number of templates of one kind per chat.
+pub const MAX_TEMPLATES: usize = 10;
+
+/// The maximum length of a template in characters.
+const TEMPLATE_MAX_LEN: usize = 1000;
+
+/// Represents the kind of the birthday greeting.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GreetingKind {
+    /// The greeting sent on the birthday.
+    SameDay,
+    /// The reminder sent several days before the birthday.
+    Advance,
+}
+
+/// Represents the period of the scheduled digest of birthdays.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DigestPeriod {
+    /// The digest of the birthdays of the week, sent on Mondays.
+    Weekly,
+    /// The digest of the birthdays of the month, sent on the first day of the month.
+    Monthly,
+}
+
+impl DigestPeriod {
+    /// Returns whether the digest is due on the given date.
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        match self {
+            DigestPeriod::Weekly => today.weekday() == Weekday::Mon,
+            DigestPeriod::Monthly => today.day() == 1,
+        }
+    }
+}
+
+/// Represents an error that can occur when validating a greeting template.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    /// The template is empty.
+    Empty,
+    /// The template is longer than allowed.
+    TooLong,
+    /// The template contains a placeholder that is not supported.
+    UnknownPlaceholder(String),
+    /// The template contains a curly brace without a pair.
+    UnclosedBrace,
+    /// The template doesn't refer to the person.
+    NoPerson,
+}
+
+impl TemplateError {
+    /// Returns the explanation of the error in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the explanation.
+    ///
+    /// # Returns
+    ///
+    /// A `String` explaining what is wrong with the template.
+    pub fn describe(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        match self {
+            TemplateError::Empty => tr.template_empty.to_string(),
+            TemplateError::TooLong => fill(tr.template_too_long, &[("max", &TEMPLATE_MAX_LEN)]),
+            TemplateError::UnknownPlaceholder(placeholder) => fill(
+                tr.template_unknown_placeholder,
+                &[
+                    ("placeholder", placeholder),
+                    ("placeholders", &placeholders()),
+                ],
+            ),
+            TemplateError::UnclosedBrace => tr.template_unclosed_brace.to_string(),
+            TemplateError::NoPerson => tr.template_no_person.to_string(),
+        }
+    }
+}
+
+/// Returns the list of the supported placeholders, e.g. "{name}, {mention}".
+pub fn placeholders() -> String {
+    PLACEHOLDERS
+        .iter()
+        .map(|placeholder| format!("{{{}}}", placeholder))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Validates the greeting template entered by an administrator.
+/// The template must refer to the person with `{name}` or `{mention}` and may contain only the
+/// supported placeholders.
+///
+/// # Arguments
+///
+/// * `input` - The template to validate.
+///
+/// # Returns
+///
+/// The trimmed template if it is valid, otherwise a `TemplateError` explaining the problem.
+pub fn validate_template(input: &str) -> Result<String, TemplateError> {
+    let template = input.trim();
+
+    if template.is_empty() {
+        return Err(TemplateError::Empty);
+    }
+    if template.chars().count() > TEMPLATE_MAX_LEN {
+        return Err(TemplateError::TooLong);
+    }
+
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err(TemplateError::UnclosedBrace);
+        }
+        let end = rest[start..]
+            .find('}')
+            .ok_or(TemplateError::UnclosedBrace)?;
+        let placeholder = &rest[start + 1..start + end];
+        if placeholder.contains('{') {
+            return Err(TemplateError::UnclosedBrace);
+        }
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Err(TemplateError::UnknownPlaceholder(placeholder.to_string()));
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    if !template.contains("{name}") && !template.contains("{mention}") {
+        return Err(TemplateError::NoPerson);
+    }
+
+    Ok(template.to_string())
+}
+
+/// Returns the default template of the greeting from the message catalogue.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the greeting.
+/// * `event` - The kind of the event.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// The default template for the greetings of the given kind for the events of the given kind.
+pub fn default_template(kind: GreetingKind, event: EventKind, lang: Language) -> &'static str {
+    let tr = lang.messages();
+    match (kind, event) {
+        (GreetingKind::SameDay, EventKind::Birthday) => tr.reminder,
+        (GreetingKind::SameDay, EventKind::WorkAnniversary) => tr.work_anniversary_reminder,
+        (GreetingKind::SameDay, EventKind::Wedding) => tr.wedding_reminder,
+        (GreetingKind::SameDay, EventKind::NameDay) => tr.name_day_reminder,
+        (GreetingKind::SameDay, EventKind::Memorial) => tr.memorial_reminder,
+        (GreetingKind::Advance, EventKind::Birthday) => tr.advance_reminder,
+        (GreetingKind::Advance, _) => tr.event_advance_reminder,
+    }
+}
+
+/// Renders the greeting for the birthday using one of the templates chosen at random.
+/// Templates with the `{age}` or `{years}` placeholders are skipped if the year is unknown. If no
+/// template fits, the default one from the message catalogue is used.
+///
+/// # Arguments
+///
+/// * `templates` - The templates set by the administrators of the chat for the kind of the event.
+/// * `kind` - The kind of the greeting.
+/// * `birthday` - The birthday to greet.
+/// * `today` - The current date.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// A `String` with the greeting formatted for the HTML parse mode.
+pub fn render_greeting(
+    templates: &[String],
+    kind: GreetingKind,
+    birthday: &Birthday,
+    today: NaiveDate,
+    lang: Language,
+) -> String {
+    let tr = lang.messages();
+    let date = birthday.next_date(today).unwrap_or(today);
+    let age = birthday.year.map(|year| date.year() - year);
+
+    let default = default_template(kind, birthday.kind, lang);
+    let suitable = templates
+        .iter()
+        .filter(|template| {
+            age.is_some() || !(template.contains("{age}") || template.contains("{years}"))
+        })
+        .collect::<Vec<_>>();
+    let template = suitable
+        .choose(&mut rand::thread_rng())
+        .map_or(default, |template| template.as_str());
+
+    let days = (date - today).num_days();
+    let days_left = format!("{} {}", days, lang.plural(days, &tr.days));
+    let years = age.map_or_else(String::new, |age| {
+        format!("{} {}", age, lang.plural(age as i64, &tr.years))
+    });
+
+    fill(
+        &html::escape(template),
+        &[
+            ("name", &html::escape(&birthday.name)),
+            ("mention", &birthday.mention()),
+            ("age", &age.unwrap_or_default()),
+            ("years", &years),
+            ("event", &birthday.kind.name(lang)),
+            ("days_left", &days_left),
+        ],
+    )
+}
+
+/// Renders the digest of all birthdays celebrated today and the advance reminders in one message.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays to remind about with the kinds of the reminders.
+/// * `today` - The current date.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// A `String` with the digest formatted for the HTML parse mode.
+pub fn render_digest(
+    birthdays: &[(GreetingKind, &Birthday)],
+    today: NaiveDate,
+    lang: Language,
+) -> String {
+    let tr = lang.messages();
+    let mut sections = Vec::new();
+
+    // Memorial dates are not celebrated, so they get their own section without the festive emoji.
+    let same_day = birthdays
+        .iter()
+        .filter(|(kind, birthday)| {
+            *kind == GreetingKind::SameDay && birthday.kind != EventKind::Memorial
+        })
+        .map(|(_, birthday)| {
+            format!(
+                "🎉 {}{}\n",
+                birthday.mention(),
+                html::escape(&birthday.kind_label(lang))
+            )
+        })
+        .collect::<String>();
+    if !same_day.is_empty() {
+        sections.push(format!("{}\n{}", tr.today_header, same_day));
+    }
+
+    let memorial = birthdays
+        .iter()
+        .filter(|(kind, birthday)| {
+            *kind == GreetingKind::SameDay && birthday.kind == EventKind::Memorial
+        })
+        .map(|(_, birthday)| format!("🕯 {}\n", birthday.mention()))
+        .collect::<String>();
+    if !memorial.is_empty() {
+        sections.push(format!("{}\n{}", tr.memorial_header, memorial));
+    }
+
+    let advance = birthdays
+        .iter()
+        .filter(|(kind, _)| *kind == GreetingKind::Advance)
+        .collect::<Vec<_>>();
+    if let Some((_, first)) = advance.first() {
+        let days = first.days_until(today).unwrap_or_default();
+        let days_left = format!("{} {}", days, lang.plural(days, &tr.days));
+        let list = advance
+            .iter()
+            .map(|(_, birthday)| {
+                format!(
+                    "🔔 {}{}\n",
+                    birthday.mention(),
+                    html::escape(&birthday.kind_label(lang))
+                )
+            })
+            .collect::<String>();
+        sections.push(format!(
+            "{}\n{}",
+            fill(tr.digest_advance_header, &[("days_left", &days_left)]),
+            list
+        ));
+    }
+
+    if birthdays
+        .iter()
+        .any(|(_, birthday)| birthday.kind != EventKind::Memorial)
+    {
+        sections.push(tr.digest_footer.to_string());
+    }
+    sections.join("\n")
+}
+
+/// Renders the scheduled digest of the birthdays of the week or the month.
+/// The people whose reminders are restricted by the rules of their tags are not listed. In the
+/// surprise mode, only the birthdays celebrated today are listed, like in the daily reminders.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays of the chat.
+/// * `period` - The period of the digest.
+/// * `today` - The first day of the period.
+/// * `settings` - The settings of the chat.
+///
+/// # Returns
+///
+/// Some `String` with the digest if there are birthdays in the period, otherwise None.
+pub fn render_period_digest(
+    birthdays: &Birthdays,
+    period: DigestPeriod,
+    today: NaiveDate,
+    settings: &Settings,
+) -> Option<String> {
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let (header, birthdays) = match period {
+        DigestPeriod::Weekly => (
+            tr.weekly_digest_header.to_string(),
+            birthdays
+                .upcoming(today, usize::MAX, &[])
+                .into_iter()
+                .filter(|(_, days)| *days < 7)
+                .map(|(birthday, _)| birthday)
+                .collect::<Vec<_>>(),
+        ),
+        DigestPeriod::Monthly => (
+            fill(
+                tr.monthly_digest_header,
+                &[("month", &tr.month_names[today.month0() as usize])],
+            ),
+            birthdays.in_month(today.month()),
+        ),
+    };
+    let birthdays = birthdays
+        .into_iter()
+        .filter(|birthday| settings.delivery_rule(birthday) == DeliveryRule::All)
+        .filter(|birthday| !settings.surprise_mode || birthday.days_until(today) == Some(0))
+        .collect::<Vec<_>>();
+
+    if birthdays.is_empty() {
+        return None;
+    }
+
+    let list = birthdays
+        .iter()
+        .map(|birthday| {
+            if birthday.username.is_empty() {
+                format!(
+                    "{} {} — {}{}\n",
+                    birthday.kind.emoji(),
+                    birthday.date,
+                    birthday.name,
+                    birthday.kind_label(lang)
+                )
+            } else {
+                format!(
+                    "{} {} — {} ({}){}\n",
+                    birthday.kind.emoji(),
+                    birthday.date,
+                    birthday.name,
+                    birthday.username,
+                    birthday.kind_label(lang)
+                )
+            }
+        })
+        .collect::<String>();
+
+    Some(format!("{}\n{}", header, list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use teloxide::types::UserId;
+
+    #[test]
+    fn greeting_does_not_expand_placeholders_in_names() {
+        let birthday = Birthday {
+            name: "{mention} {days_left}".to_string(),
+            date: "05-03".to_string(),
+            user_id: Some(UserId(42)),
+            ..Default::default()
+        };
+        let today = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+        let greeting = render_greeting(
+            &["Happy birthday, {name}!".to_string()],
+            GreetingKind::SameDay,
+            &birthday,
+            today,
+            Language::English,
+        );
+        assert_eq!(greeting, "Happy birthday, {mention} {days_left}!");
+    }
+}
@@ -1,69 +1,34 @@
-use teloxide::prelude::{Message, Requester, ResponseResult};
-use teloxide::types::InputFile;
-use teloxide::Bot;
-
-use crate::handles::BUSY_MSG;
-use crate::{Birthdays, ConfigParameters, State};
-
-/// The message to send when the user sends a JSON file.
-const JSON_MSG: &str =
-    "Отправьте мне заполненный JSON файл с указанием дней рождений. Я отправил вам пример того, \
-как должен выглядеть файл";
-
-/// The message to send when the user sends a birthday to add.
-const ADD_MSG: &str = "Отправьте мне день рождения в формате 'Имя Фамилия, ДД-ММ, @username' или 'Имя Фамилия, ДД-MM'. \
-    Например, 'Иван Иванов, 01-01, @ivan' или 'Иван Иванов, 01-01'.\n \
-    Для выхода из режима обновления дней рождений введите команду /cancel";
-
-/// The message to send when the user wants to remove a birthday.
-const REMOVE_MSG: &str = "Отправьте мне номер дня рождения, который хотите удалить. \n \
-    Для выхода из режима обновления дней рождений введите команду /cancel";
-
-/// The message to send when the user cancels the birthday addition mode.
-const CANCEL_MSG: &str =
-    "Режим обнолвения дней рождений отключен. Для активации уведомлений выполните команду /active";
-
-/// The message to send when the user tries to cancel the birthday addition mode without adding any birthdays.
-const CANCEL_EMPTY_LIST_MSG: &str = "Режим обновления дней рождений отключен. \
-    Ни одного дня рождения не добавлено.";
-
-/// The message to send when the user tries to cancel the birthday addition mode when it is already disabled.
-const CANCEL_ALREADY_DISABLED_MSG: &str = "Режим обновления дней рождений уже отключен.";
+use std::collections::HashMap;
 
-/// The message to send when the user tries to activate the bot.
-const ACTIVE_MSG: &str = "Уведомления от меня активны!🎉";
-
-/// The message to send when the user tries to activate the bot when it is already active.
-const ACTIVE_ALREADY_ACTIVE_MSG: &str = "Уведомления от меня уже активны!";
-
-/// The message to send when the user tries to activate the bot without adding any birthdays via JSON.
-const ACTIVE_WAITING_JSON_MSG: &str =
-    "Прежде чем активировать уведомления, отправьте мне заполненный JSON файл с днями рождения или выполните \
-    команду /cancel для выхода из режима обновления дней рождений";
-
-/// The message to send when the user tries to activate the bot without adding any birthdays.
-const ACTIVE_EMPTY_LIST: &str =
-    "Ни одного дня рождения не добавлено 😞, поэтому уведомления от меня не активны. \
-    Для добавления дней рождений выполните команду /add или /addmany";
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use teloxide::payloads::{PinChatMessageSetters, SendMessageSetters, SendPhotoSetters};
+use teloxide::prelude::{ChatId, Message, Requester, ResponseResult};
+use teloxide::types::{InputFile, ParseMode};
+use teloxide::Bot;
 
-/// The message to send when the user tries to activate the bot without adding any birthdays via `add` command.
-const ACTIVE_WAITING_BIR_MSG: &str =
-    "Прежде чем активировать уведомления, выполните команду /cancel, чтобы выйти из режима обновления дней рождений";
+use crate::birthday::Gift;
+use crate::card::CardTheme;
+use crate::collection::Collection;
+use crate::greeting::{self, DigestPeriod, GreetingKind};
+use crate::i18n::fill;
+use crate::poll::{self, CelebrationPoll};
+use crate::schedule::{self, ScheduledEvent};
+use crate::{
+    Birthday, Birthdays, CardMode, ConfigParameters, DateOrder, DeliveryRule, EventKind, Language,
+    State,
+};
 
-/// The message to send when the user tries to disable the bot.
-const DISABLE_MSG: &str = "Уведомления от меня отключены!";
+/// The maximum number of days before the birthday to send the advance reminder.
+const MAX_ADVANCE_DAYS: u32 = 30;
 
-/// The message to send when the user tries to disable the bot when it is already disabled.
-const DISABLE_ALREADY_DISABLED_MSG: &str = "Уведомления от меня уже отключены!";
+/// The maximum number of notes about a person.
+const MAX_NOTES: usize = 20;
 
-/// The message to send when the user tries to disable the bot without adding any birthdays via `add` or `addmany` commands.
-const DISABLE_WAITING_MSG: &str =
-    "Прежде чем отключить уведомления, выполните команду /cancel для выхода из режима обновления дней рождений";
+/// The maximum number of gifts in the history of a person.
+const MAX_GIFTS: usize = 50;
 
-/// The message to send when the user tries to disable the bot without adding any birthdays.
-const DISABLE_EMPTY_LIST: &str =
-    "Ни одного дня рождения не добавлено 😞, поэтому уведомления от меня не активны. \
-    Для добавления дней рождений выполните команду /add или /addmany";
+/// The maximum length of a note or a description of a gift in characters.
+const NOTE_MAX_LEN: usize = 500;
 
 /// Handles admin commands for the bot.
 ///
@@ -84,40 +49,139 @@ pub async fn admin_commands_handler(
     cfg: ConfigParameters,
 ) -> ResponseResult<()> {
     match cmd {
-        super::AdminCommands::Add => handle_add_command(bot, msg, cfg).await,
+        super::AdminCommands::Add(args) => handle_add_command(bot, msg, cfg, args).await,
         super::AdminCommands::AddMany => handle_add_many_command(bot, msg, cfg).await,
         super::AdminCommands::Cancel => handle_cancel_command(bot, msg, cfg).await,
         super::AdminCommands::Active => handle_active_command(bot, msg, cfg).await,
         super::AdminCommands::Disable => handle_disable_command(bot, msg, cfg).await,
-        super::AdminCommands::List => handle_list_command(bot, msg, cfg).await,
+        super::AdminCommands::List(kind) => handle_list_command(bot, msg, cfg, kind).await,
+        super::AdminCommands::Export(tags) => handle_export_command(bot, msg, cfg, tags).await,
         super::AdminCommands::Remove => handle_remove_command(bot, msg, cfg).await,
+        super::AdminCommands::Privacy => handle_privacy_command(bot, msg, cfg).await,
+        super::AdminCommands::SelfRegistration => {
+            handle_self_registration_command(bot, msg, cfg).await
+        }
+        super::AdminCommands::DateOrder => handle_date_order_command(bot, msg, cfg).await,
+        super::AdminCommands::Language(code) => handle_language_command(bot, msg, cfg, code).await,
+        super::AdminCommands::Advance(days) => handle_advance_command(bot, msg, cfg, days).await,
+        super::AdminCommands::Greeting(args) => {
+            handle_greeting_command(bot, msg, cfg, args, GreetingKind::SameDay).await
+        }
+        super::AdminCommands::AdvanceGreeting(args) => {
+            handle_greeting_command(bot, msg, cfg, args, GreetingKind::Advance).await
+        }
+        super::AdminCommands::Combine => handle_combine_command(bot, msg, cfg).await,
+        super::AdminCommands::Digest(period) => handle_digest_command(bot, msg, cfg, period).await,
+        super::AdminCommands::Surprise => handle_surprise_command(bot, msg, cfg).await,
+        super::AdminCommands::NameDays => handle_name_days_command(bot, msg, cfg).await,
+        super::AdminCommands::Collect(args) => handle_collect_command(bot, msg, cfg, args).await,
+        super::AdminCommands::Event(args) => handle_event_command(bot, msg, cfg, args).await,
+        super::AdminCommands::Events => handle_events_command(bot, msg, cfg).await,
+        super::AdminCommands::CancelEvent(id) => {
+            handle_cancel_event_command(bot, msg, cfg, id).await
+        }
+        super::AdminCommands::Tags(args) => handle_tags_command(bot, msg, cfg, args).await,
+        super::AdminCommands::TagRule(args) => handle_tag_rule_command(bot, msg, cfg, args).await,
+        super::AdminCommands::Notes(index) => handle_notes_command(bot, msg, cfg, index).await,
+        super::AdminCommands::Note(args) => handle_note_command(bot, msg, cfg, args).await,
+        super::AdminCommands::Gift(args) => handle_gift_command(bot, msg, cfg, args).await,
+        super::AdminCommands::Topic => handle_topic_command(bot, msg, cfg).await,
+        super::AdminCommands::Pin => handle_pin_command(bot, msg, cfg).await,
+        super::AdminCommands::Cards(mode) => handle_cards_command(bot, msg, cfg, mode).await,
+        super::AdminCommands::CardTheme(theme) => {
+            handle_card_theme_command(bot, msg, cfg, theme).await
+        }
+        super::AdminCommands::CardPreview(args) => {
+            handle_card_preview_command(bot, msg, cfg, args).await
+        }
+        super::AdminCommands::Calendar(month) => {
+            handle_calendar_command(bot, msg, cfg, month).await
+        }
+        super::AdminCommands::Poll(args) => handle_poll_command(bot, msg, cfg, args).await,
+        super::AdminCommands::CancelPoll(id) => handle_cancel_poll_command(bot, msg, cfg, id).await,
     }
 }
 
 /// Handles the `add` command for the bot.
-/// This function sets the bot state to `WaitingBirthday` for the chat and sends a message
-/// to the chat with instructions on how to add a birthday.
+/// Without arguments, this function sets the bot state to `WaitingBirthday` for the chat and sends
+/// a message to the chat with instructions on how to add a birthday.
+///
+/// If the command is a reply to a member's message, the arguments are processed as the date of
+/// birth, optionally prefixed with the kind of the event, and the name, username and user ID are
+/// taken from the member. Otherwise, the arguments are processed as a birthday in the same format
+/// as in the adding mode.
 ///
 /// # Arguments
 ///
 /// * `bot` - The bot instance.
 /// * `msg` - The message triggering the command.
 /// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The arguments of the command.
 ///
 /// # Returns
 ///
 /// A `ResponseResult` indicating the success or failure of the command.
-async fn handle_add_command(bot: Bot, msg: Message, cfg: ConfigParameters) -> ResponseResult<()> {
+async fn handle_add_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
     log::info!("Add command received from chat id {}", msg.chat.id);
 
+    if !args.trim().is_empty() {
+        let settings = cfg.b_map.read().await.get_settings(&msg.chat.id);
+
+        return match super::common::replied_user(&msg) {
+            Some(user) => match crate::parser::parse_kind(&args).and_then(|(kind, rest)| {
+                let (tags, date) = crate::parser::parse_tags(rest)?;
+                crate::parser::parse_date(&date, settings.date_order)
+                    .map(|(date, year)| (kind, date, year, tags))
+            }) {
+                Ok((kind, date, year, tags)) => {
+                    let birthday = Birthday {
+                        name: user.full_name(),
+                        date,
+                        username: user
+                            .username
+                            .as_ref()
+                            .map(|username| format!("@{}", username))
+                            .unwrap_or_default(),
+                        year,
+                        user_id: Some(user.id),
+                        kind,
+                        tags,
+                        ..Default::default()
+                    };
+                    super::common::add_birthday(birthday, bot, msg.chat.id, cfg).await
+                }
+                Err(err) => {
+                    let tr = settings.language.messages();
+                    bot.send_message(
+                        msg.chat.id,
+                        fill(
+                            tr.add_reply_invalid_date,
+                            &[("error", &err.describe(settings.language))],
+                        ),
+                    )
+                    .await?;
+                    Ok(())
+                }
+            },
+            None => super::common::add_handler(&args, None, bot, msg.chat.id, cfg).await,
+        };
+    }
+
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
 
     match b_map.update_state(&msg.chat.id, State::WaitingBirthday) {
         Ok(_) => {
-            bot.send_message(msg.chat.id, ADD_MSG).await?;
+            bot.send_message(msg.chat.id, tr.add).await?;
         }
         Err(_) => {
-            bot.send_message(msg.chat.id, BUSY_MSG).await?;
+            bot.send_message(msg.chat.id, tr.busy).await?;
         }
     }
     Ok(())
@@ -144,16 +208,18 @@ async fn handle_add_many_command(
     log::info!("AddMany command received from chat id {}", msg.chat.id);
 
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
 
     match b_map.update_state(&msg.chat.id, State::WaitingJson) {
         Ok(_) => {
-            bot.send_message(msg.chat.id, JSON_MSG).await?;
+            bot.send_message(msg.chat.id, tr.json).await?;
             bot.send_document(msg.chat.id, InputFile::file(super::SAMPLE_JSON_FILE_PATH))
                 .await?;
             Ok(())
         }
         Err(_) => {
-            bot.send_message(msg.chat.id, BUSY_MSG).await?;
+            bot.send_message(msg.chat.id, tr.busy).await?;
             Ok(())
         }
     }
@@ -180,32 +246,34 @@ async fn handle_cancel_command(
     log::info!("Cancel command received from chat id {}", msg.chat.id);
 
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
 
     match b_map.get_mut(&msg.chat.id) {
         Some((state, _)) => match state {
             State::WaitingBirthday | State::WaitingJson | State::WaitingRemoving => {
                 match b_map.update_state(&msg.chat.id, State::Disabled) {
                     Ok(_) => {
-                        bot.send_message(msg.chat.id, CANCEL_MSG).await?;
+                        bot.send_message(msg.chat.id, tr.cancel).await?;
                         let (_, birthdays) = b_map.get(&msg.chat.id).unwrap();
-                        bot.send_message(msg.chat.id, birthdays.list().as_str())
+                        bot.send_message(msg.chat.id, birthdays.list(lang, None, &[]).as_str())
                             .await?;
                     }
                     Err(_) => {
-                        bot.send_message(msg.chat.id, BUSY_MSG).await?;
+                        bot.send_message(msg.chat.id, tr.busy).await?;
                     }
                 }
             }
             _ => {
-                bot.send_message(msg.chat.id, CANCEL_ALREADY_DISABLED_MSG)
+                bot.send_message(msg.chat.id, tr.cancel_already_disabled)
                     .await?;
             }
         },
         None => {
             if let Err(_) = b_map.insert(msg.chat.id, State::Disabled, Birthdays::default()) {
-                bot.send_message(msg.chat.id, BUSY_MSG).await?;
+                bot.send_message(msg.chat.id, tr.busy).await?;
             } else {
-                bot.send_message(msg.chat.id, CANCEL_EMPTY_LIST_MSG).await?;
+                bot.send_message(msg.chat.id, tr.cancel_empty_list).await?;
             }
         }
     }
@@ -215,33 +283,176 @@ async fn handle_cancel_command(
 
 /// Handles the `list` command for the bot.
 /// This function sends a message to the chat with the list of current birthdays for the chat.
+/// If the kind of the event or the tag is given, only the matching events are listed.
 ///
 /// # Arguments
 ///
 /// * `bot` - The bot instance.
 /// * `msg` - The message triggering the command.
 /// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The kind of the events and the hashtags to filter by, e.g. "work #backend", or an
+///   empty string to list all events. The events must have all the hashtags.
 ///
 /// # Returns
 ///
 /// A `ResponseResult` indicating the success or failure of the command.
-async fn handle_list_command(bot: Bot, msg: Message, cfg: ConfigParameters) -> ResponseResult<()> {
+async fn handle_list_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
     log::info!("List command received from chat id {}", msg.chat.id);
 
     let b_map = cfg.b_map.read().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+
+    let (tags, kind) = match crate::parser::parse_tags(&args) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+    let kind = match kind.trim() {
+        "" => None,
+        kind => match EventKind::parse(kind) {
+            Some(kind) => Some(kind),
+            None => {
+                bot.send_message(
+                    msg.chat.id,
+                    fill(
+                        lang.messages().list_usage,
+                        &[("kinds", &EventKind::names())],
+                    ),
+                )
+                .await?;
+                return Ok(());
+            }
+        },
+    };
     let birthdays_default = Birthdays::default();
     let birthdays = b_map
         .get(&msg.chat.id)
         .map(|(_, birthdays)| birthdays)
         .unwrap_or(&birthdays_default);
 
-    bot.send_message(msg.chat.id, birthdays.list()).await?;
+    bot.send_message(msg.chat.id, birthdays.list(lang, kind, &tags))
+        .await?;
+
+    Ok(())
+}
+
+/// Handles the `export` command for the bot.
+/// This function sends the events of the chat in a JSON file in the format accepted by `/addmany`,
+/// optionally only the events with all the given tags. The notes and the gift history are left out
+/// unless they are requested explicitly. In groups, the file with the notes is sent to the
+/// administrator in private messages.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The hashtags to filter by, e.g. "#backend", and the optional "notes" word to include
+///   the notes, or an empty string to export all events.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_export_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Export command received from chat id {}", msg.chat.id);
+
+    let b_map = cfg.b_map.read().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let (tags, with_notes) = match crate::parser::parse_tags(&args) {
+        Ok((tags, rest)) => match crate::utils::normalize_text(&rest).as_str() {
+            "" => (tags, false),
+            "notes" | "заметки" => (tags, true),
+            _ => {
+                bot.send_message(msg.chat.id, tr.export_usage).await?;
+                return Ok(());
+            }
+        },
+        Err(err) => {
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+    let birthdays = b_map
+        .get(&msg.chat.id)
+        .map(|(_, birthdays)| {
+            birthdays
+                .iter()
+                .filter(|birthday| birthday.has_tags(&tags))
+                .map(|birthday| {
+                    if with_notes {
+                        birthday.clone()
+                    } else {
+                        Birthday {
+                            notes: Vec::new(),
+                            gifts: Vec::new(),
+                            ..birthday.clone()
+                        }
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    drop(b_map);
+
+    if birthdays.is_empty() {
+        bot.send_message(msg.chat.id, tr.list_empty).await?;
+        return Ok(());
+    }
+
+    let json = match serde_json::to_vec_pretty(&birthdays) {
+        Ok(json) => json,
+        Err(e) => {
+            log::error!(
+                "Failed to export birthdays of chat id {}: {}",
+                msg.chat.id,
+                e
+            );
+            bot.send_message(msg.chat.id, tr.export_failed).await?;
+            return Ok(());
+        }
+    };
+    let file = InputFile::memory(json).file_name("birthdays.json");
+
+    // The notes are never shown to regular members, so they are sent privately from groups.
+    let user = match msg.from() {
+        Some(user) if with_notes && !msg.chat.is_private() => user,
+        _ => {
+            bot.send_document(msg.chat.id, file).await?;
+            return Ok(());
+        }
+    };
+    match bot.send_document(ChatId(user.id.0 as i64), file).await {
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.notes_sent_privately)
+                .await?;
+        }
+        Err(e) => {
+            log::warn!("Export not sent to user {}: {}", user.id, e);
+            bot.send_message(msg.chat.id, tr.notes_private_failed)
+                .await?;
+        }
+    }
 
     Ok(())
 }
 
 /// Handles the `active` command for the bot.
 /// This function activates the bot for the chat and sends a message to the chat to confirm the activation.
+/// The chat can be activated if it has birthdays or scheduled events.
 ///
 /// # Arguments
 ///
@@ -260,35 +471,45 @@ async fn handle_active_command(
     log::info!("Active command received from chat id {}", msg.chat.id);
 
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+    let has_events = !b_map.events(&msg.chat.id).is_empty();
 
     match b_map.get_mut(&msg.chat.id) {
         Some((state, birthdays)) => match state {
             State::Disabled => {
-                if birthdays.is_empty() {
-                    bot.send_message(msg.chat.id, ACTIVE_EMPTY_LIST).await?;
+                if birthdays.is_empty() && !has_events {
+                    bot.send_message(msg.chat.id, tr.active_empty_list).await?;
                 } else {
                     *state = State::Active;
-                    bot.send_message(msg.chat.id, ACTIVE_MSG).await?;
+                    bot.send_message(msg.chat.id, tr.active).await?;
                 }
             }
             State::Active => {
-                bot.send_message(msg.chat.id, ACTIVE_ALREADY_ACTIVE_MSG)
+                bot.send_message(msg.chat.id, tr.active_already_active)
                     .await?;
             }
             State::WaitingJson => {
-                bot.send_message(msg.chat.id, ACTIVE_WAITING_JSON_MSG)
+                bot.send_message(msg.chat.id, tr.active_waiting_json)
                     .await?;
             }
             State::WaitingBirthday | State::WaitingRemoving => {
-                bot.send_message(msg.chat.id, ACTIVE_WAITING_BIR_MSG)
+                bot.send_message(msg.chat.id, tr.active_waiting_birthday)
                     .await?;
             }
         },
         None => {
-            if let Err(_) = b_map.insert(msg.chat.id, State::Disabled, Birthdays::default()) {
-                bot.send_message(msg.chat.id, BUSY_MSG).await?;
+            let state = if has_events {
+                State::Active
             } else {
-                bot.send_message(msg.chat.id, ACTIVE_EMPTY_LIST).await?;
+                State::Disabled
+            };
+            if let Err(_) = b_map.insert(msg.chat.id, state, Birthdays::default()) {
+                bot.send_message(msg.chat.id, tr.busy).await?;
+            } else if has_events {
+                bot.send_message(msg.chat.id, tr.active).await?;
+            } else {
+                bot.send_message(msg.chat.id, tr.active_empty_list).await?;
             }
         }
     }
@@ -316,26 +537,28 @@ async fn handle_disable_command(
     log::info!("Disable command received from chat id {}", msg.chat.id);
 
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
 
     match b_map.get_mut(&msg.chat.id) {
         Some((state, _)) => match state {
             State::Disabled => {
-                bot.send_message(msg.chat.id, DISABLE_ALREADY_DISABLED_MSG)
+                bot.send_message(msg.chat.id, tr.disable_already_disabled)
                     .await?;
             }
             State::Active => {
                 *state = State::Disabled;
-                bot.send_message(msg.chat.id, DISABLE_MSG).await?;
+                bot.send_message(msg.chat.id, tr.disable).await?;
             }
             State::WaitingJson | State::WaitingBirthday | State::WaitingRemoving => {
-                bot.send_message(msg.chat.id, DISABLE_WAITING_MSG).await?;
+                bot.send_message(msg.chat.id, tr.disable_waiting).await?;
             }
         },
         None => {
             if let Err(_) = b_map.insert(msg.chat.id, State::Disabled, Birthdays::default()) {
-                bot.send_message(msg.chat.id, BUSY_MSG).await?;
+                bot.send_message(msg.chat.id, tr.busy).await?;
             } else {
-                bot.send_message(msg.chat.id, DISABLE_EMPTY_LIST).await?;
+                bot.send_message(msg.chat.id, tr.disable_empty_list).await?;
             }
         }
     }
@@ -364,17 +587,2040 @@ async fn handle_remove_command(
     log::info!("Remove command received from chat id {}", msg.chat.id);
 
     let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
 
     match b_map.update_state(&msg.chat.id, State::WaitingRemoving) {
         Ok(_) => {
-            bot.send_message(msg.chat.id, REMOVE_MSG).await?;
+            bot.send_message(msg.chat.id, tr.remove).await?;
             let (_, birthdays) = b_map.get(&msg.chat.id).unwrap();
-            bot.send_message(msg.chat.id, birthdays.list()).await?;
+            bot.send_message(msg.chat.id, birthdays.list(lang, None, &[]))
+                .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `privacy` command for the bot.
+/// This function toggles the visibility of the list of birthdays for regular members of the chat
+/// and sends a message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_privacy_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Privacy command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.is_private = !settings.is_private;
+    let is_private = settings.is_private;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if is_private => {
+            bot.send_message(msg.chat.id, tr.privacy_enabled).await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.privacy_disabled).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `selfregistration` command for the bot.
+/// This function toggles whether members of the chat can add their own birthdays and sends a
+/// message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_self_registration_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!(
+        "SelfRegistration command received from chat id {}",
+        msg.chat.id
+    );
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.allow_self_registration = !settings.allow_self_registration;
+    let allow_self_registration = settings.allow_self_registration;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if allow_self_registration => {
+            bot.send_message(msg.chat.id, tr.self_registration_enabled)
+                .await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.self_registration_disabled)
+                .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `dateorder` command for the bot.
+/// This function toggles the order of the day and month in numeric dates for the chat and sends a
+/// message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_date_order_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("DateOrder command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.date_order = match settings.date_order {
+        DateOrder::DayMonth => DateOrder::MonthDay,
+        DateOrder::MonthDay => DateOrder::DayMonth,
+    };
+    let date_order = settings.date_order;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            let text = match date_order {
+                DateOrder::DayMonth => tr.date_order_day_month,
+                DateOrder::MonthDay => tr.date_order_month_day,
+            };
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `language` command for the bot.
+/// This function changes the language of the messages sent to the chat and sends a message to the
+/// chat to confirm the change. If the language is not specified or not supported, the available
+/// languages are listed.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `code` - The code or the name of the language.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_language_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    code: String,
+) -> ResponseResult<()> {
+    log::info!("Language command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+
+    let lang = match Language::parse(&code) {
+        Some(lang) => lang,
+        None => {
+            let languages = Language::ALL
+                .iter()
+                .map(|lang| format!("{} ({})", lang.code(), lang.name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    settings.language.messages().language_usage,
+                    &[("languages", &languages)],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    settings.language = lang;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            bot.send_message(msg.chat.id, lang.messages().language_changed)
+                .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, lang.messages().busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `advance` command for the bot.
+/// This function sets how many days before the birthday the advance reminder is sent and sends a
+/// message to the chat to confirm the change. Zero days disable the advance reminders.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `days` - The number of days before the birthday.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_advance_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    days: String,
+) -> ResponseResult<()> {
+    log::info!("Advance command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let days = match days.trim().parse::<u32>() {
+        Ok(days) if days <= MAX_ADVANCE_DAYS => days,
+        _ => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.advance_usage, &[("max", &MAX_ADVANCE_DAYS)]),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.advance_days = days;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if days > 0 => {
+            let days_word = lang.plural(days as i64, &tr.days);
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    tr.advance_enabled,
+                    &[("days", &days), ("days_word", &days_word)],
+                ),
+            )
+            .await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.advance_disabled).await?;
         }
         Err(_) => {
-            bot.send_message(msg.chat.id, BUSY_MSG).await?;
+            bot.send_message(msg.chat.id, tr.busy).await?;
         }
     }
 
     Ok(())
 }
+
+/// Handles the `greeting` and `advancegreeting` commands for the bot.
+/// Without arguments, this function lists the greeting templates of the given kind. The `remove`
+/// argument with a number removes the template, the `reset` argument removes all templates, and
+/// any other text is validated and added as a new template. The templates of other events are
+/// managed by prefixing the arguments with the kind of the event, e.g. `work: remove 1`.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The arguments of the command.
+/// * `kind` - The kind of the greeting templates.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_greeting_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+    kind: GreetingKind,
+) -> ResponseResult<()> {
+    log::info!(
+        "Greeting command ({:?}) received from chat id {}",
+        kind,
+        msg.chat.id
+    );
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let command = match kind {
+        GreetingKind::SameDay => "/greeting",
+        GreetingKind::Advance => "/advancegreeting",
+    };
+
+    // The template itself may contain a colon, so only a known kind is treated as the prefix.
+    let (event, args) = match crate::parser::parse_kind(&args) {
+        Ok((event, rest)) => (event, rest),
+        Err(_) => (EventKind::Birthday, args.as_str()),
+    };
+    let command = if event.is_birthday() {
+        command.to_string()
+    } else {
+        format!("{} {}:", command, event.keyword())
+    };
+
+    let args = args.trim();
+    let (action, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+
+    let text = match action.to_lowercase().as_str() {
+        "" => {
+            let header = match (kind, event) {
+                (GreetingKind::SameDay, EventKind::Birthday) => tr.greetings_header.to_string(),
+                (GreetingKind::Advance, EventKind::Birthday) => {
+                    tr.advance_greetings_header.to_string()
+                }
+                (GreetingKind::SameDay, event) => {
+                    fill(tr.event_greetings_header, &[("event", &event.name(lang))])
+                }
+                (GreetingKind::Advance, event) => fill(
+                    tr.event_advance_greetings_header,
+                    &[("event", &event.name(lang))],
+                ),
+            };
+            let templates = settings.templates(kind, event);
+            let list = if templates.is_empty() {
+                let default = greeting::default_template(kind, event, lang);
+                fill(tr.greetings_default, &[("template", &default)])
+            } else {
+                templates
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, template)| format!("{}. {}\n", idx + 1, template))
+                    .collect::<String>()
+            };
+            let usage = fill(
+                tr.greetings_usage,
+                &[
+                    ("command", &command),
+                    ("placeholders", &greeting::placeholders()),
+                    ("kinds", &EventKind::names()),
+                ],
+            );
+            bot.send_message(msg.chat.id, format!("{}\n{}\n\n{}", header, list, usage))
+                .await?;
+            return Ok(());
+        }
+        "remove" => {
+            let templates = settings.templates_mut(kind, event);
+            match crate::utils::parse_birthday_index(rest.trim()) {
+                Some(index) if (1..=templates.len()).contains(&index) => {
+                    templates.remove(index - 1);
+                    fill(tr.greeting_removed, &[("index", &index)])
+                }
+                _ => {
+                    bot.send_message(msg.chat.id, tr.greeting_not_found).await?;
+                    return Ok(());
+                }
+            }
+        }
+        "reset" => {
+            settings.templates_mut(kind, event).clear();
+            tr.greetings_reset.to_string()
+        }
+        _ => {
+            let template = match greeting::validate_template(args) {
+                Ok(template) => template,
+                Err(err) => {
+                    log::warn!("Invalid greeting template: {:?}", err);
+                    bot.send_message(
+                        msg.chat.id,
+                        fill(tr.invalid_template, &[("error", &err.describe(lang))]),
+                    )
+                    .await?;
+                    return Ok(());
+                }
+            };
+
+            let templates = settings.templates_mut(kind, event);
+            if templates.len() >= greeting::MAX_TEMPLATES {
+                bot.send_message(
+                    msg.chat.id,
+                    fill(
+                        tr.greetings_too_many,
+                        &[("max", &greeting::MAX_TEMPLATES), ("command", &command)],
+                    ),
+                )
+                .await?;
+                return Ok(());
+            }
+            templates.push(template.clone());
+            let index = templates.len();
+
+            // Show how the greeting looks like with a sample birthday.
+            let today = Utc::now().date_naive();
+            let days = match kind {
+                GreetingKind::SameDay => 0,
+                GreetingKind::Advance => settings.advance_days.max(1),
+            };
+            let date = today + Duration::days(days as i64);
+            let sample = Birthday {
+                name: msg
+                    .from()
+                    .map_or_else(|| "Ivan".to_string(), |user| user.first_name.clone()),
+                date: date.format("%d-%m").to_string(),
+                year: Some(date.year() - 30),
+                user_id: msg.from().map(|user| user.id),
+                kind: event,
+                ..Default::default()
+            };
+            let example = greeting::render_greeting(&[template], kind, &sample, today, lang);
+
+            format!(
+                "{}\n\n{}",
+                fill(tr.greeting_added, &[("index", &index)]),
+                example
+            )
+        }
+    };
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            bot.send_message(msg.chat.id, text)
+                .parse_mode(ParseMode::Html)
+                .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `combine` command for the bot.
+/// This function toggles whether the reminders of one day are sent as a single digest message and
+/// sends a message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_combine_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Combine command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.combine_reminders = !settings.combine_reminders;
+    let combine_reminders = settings.combine_reminders;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if combine_reminders => {
+            bot.send_message(msg.chat.id, tr.combine_enabled).await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.combine_disabled).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `digest` command for the bot.
+/// This function toggles the weekly or monthly digest of birthdays for the chat and sends a message
+/// to the chat to confirm the change. If the period is not specified or not recognized, the current
+/// digest settings are shown.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `period` - The period of the digest.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_digest_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    period: String,
+) -> ResponseResult<()> {
+    log::info!("Digest command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let tr = settings.language.messages();
+
+    let period = match period.trim().to_lowercase().as_str() {
+        "weekly" | "week" | "неделя" => DigestPeriod::Weekly,
+        "monthly" | "month" | "месяц" => DigestPeriod::Monthly,
+        _ => {
+            let status = |enabled: bool| if enabled { tr.digest_on } else { tr.digest_off };
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    tr.digest_usage,
+                    &[
+                        ("weekly", &status(settings.weekly_digest)),
+                        ("monthly", &status(settings.monthly_digest)),
+                    ],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let enabled = !settings.digest(period);
+    *settings.digest_mut(period) = enabled;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            let text = match (period, enabled) {
+                (DigestPeriod::Weekly, true) => tr.weekly_digest_enabled,
+                (DigestPeriod::Weekly, false) => tr.weekly_digest_disabled,
+                (DigestPeriod::Monthly, true) => tr.monthly_digest_enabled,
+                (DigestPeriod::Monthly, false) => tr.monthly_digest_disabled,
+            };
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `surprise` command for the bot.
+/// This function toggles the surprise mode, in which the advance reminders are sent to the
+/// subscribers of the chat in private messages instead of the chat, and sends a message to the
+/// chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_surprise_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Surprise command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.surprise_mode = !settings.surprise_mode;
+    let surprise_mode = settings.surprise_mode;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if surprise_mode => {
+            bot.send_message(msg.chat.id, tr.surprise_enabled).await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.surprise_disabled).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `namedays` command for the bot.
+/// This function toggles the announcements of the name days of the people in the list according
+/// to the Orthodox calendar, and sends a message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_name_days_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Name days command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.name_days = !settings.name_days;
+    let name_days = settings.name_days;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if name_days => {
+            bot.send_message(msg.chat.id, tr.name_days_enabled).await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.name_days_disabled).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `pin` command for the bot.
+/// This function toggles pinning the greeting of the day in the chat and sends a message to the
+/// chat to confirm the change. If the bot lacks the rights to pin messages, the administrators
+/// are warned right away.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_pin_command(bot: Bot, msg: Message, cfg: ConfigParameters) -> ResponseResult<()> {
+    log::info!("Pin command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    settings.pin_greeting = !settings.pin_greeting;
+    let pin_greeting = settings.pin_greeting;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if pin_greeting => {
+            let text = match crate::utils::can_pin_messages(&bot, msg.chat.id).await {
+                Ok(true) => tr.pin_enabled.to_string(),
+                _ => format!("{}\n{}", tr.pin_enabled, tr.pin_rights_missing),
+            };
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.pin_disabled).await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `cards` command for the bot.
+/// This function sets the way the greeting cards are sent on birthdays and sends a message to the
+/// chat to confirm the change. If the mode is not specified or not recognized, the current mode
+/// and the available modes are shown.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `mode` - The way to send the cards.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_cards_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    mode: String,
+) -> ResponseResult<()> {
+    log::info!("Cards command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let mode = match CardMode::parse(&mode) {
+        Some(mode) => mode,
+        None => {
+            let modes = CardMode::ALL
+                .iter()
+                .map(|mode| format!("{} — {}", mode.keyword(), mode.describe(lang)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    tr.cards_usage,
+                    &[
+                        ("mode", &settings.card_mode.describe(lang)),
+                        ("modes", &modes),
+                    ],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+    settings.card_mode = mode;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.cards_set, &[("mode", &mode.describe(lang))]),
+            )
+            .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `cardtheme` command for the bot.
+/// This function sets the background theme of the greeting cards and sends a message to the chat
+/// to confirm the change. If the theme is not specified or not recognized, the current theme and
+/// the available themes are shown.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `theme` - The name of the theme.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_card_theme_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    theme: String,
+) -> ResponseResult<()> {
+    log::info!("CardTheme command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let theme = match CardTheme::parse(&theme) {
+        Some(theme) => theme,
+        None => {
+            let themes = CardTheme::ALL
+                .iter()
+                .map(|theme| format!("{} — {}", theme.keyword(), theme.describe(lang)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    tr.card_theme_usage,
+                    &[
+                        ("theme", &settings.card_theme.keyword()),
+                        ("themes", &themes),
+                    ],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+    settings.card_theme = theme;
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.card_theme_set, &[("theme", &theme.keyword())]),
+            )
+            .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `cardpreview` command for the bot.
+/// This function renders the greeting card for the birthday with the given number in the list, or
+/// for the sender if the number is not specified, and sends it to the chat. The card is drawn in
+/// the given theme, or in the theme of the chat by default, so the themes can be compared before
+/// choosing one.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the birthday and the name of the theme, both optional.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_card_preview_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("CardPreview command received from chat id {}", msg.chat.id);
+
+    let b_map = cfg.b_map.read().await;
+    let settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let mut theme = settings.card_theme;
+    let mut birthday = None;
+    for arg in args.split_whitespace() {
+        if let Some(found) = CardTheme::parse(arg) {
+            theme = found;
+            continue;
+        }
+        birthday = crate::utils::parse_birthday_index(arg).and_then(|index| {
+            b_map
+                .get(&msg.chat.id)
+                .and_then(|(_, birthdays)| birthdays.iter().nth(index))
+        });
+        if birthday.is_none() {
+            bot.send_message(msg.chat.id, tr.card_preview_usage).await?;
+            return Ok(());
+        }
+    }
+
+    let birthday = birthday.cloned();
+    drop(b_map);
+
+    let today = Utc::now().date_naive();
+    let card = match (birthday, msg.from()) {
+        (Some(birthday), _) => {
+            crate::card::render_blocking(move || {
+                crate::card::render_birthday(theme, &birthday, today, lang)
+            })
+            .await
+        }
+        (None, Some(user)) => {
+            let name = user.full_name();
+            crate::card::render_blocking(move || crate::card::render(theme, &name, None, lang))
+                .await
+        }
+        (None, None) => {
+            bot.send_message(msg.chat.id, tr.card_preview_usage).await?;
+            return Ok(());
+        }
+    };
+
+    match card {
+        Ok(card) => {
+            bot.send_photo(msg.chat.id, InputFile::memory(card).file_name("card.png"))
+                .caption(fill(
+                    tr.card_preview_caption,
+                    &[("theme", &theme.keyword())],
+                ))
+                .await?;
+        }
+        Err(e) => {
+            log::error!("Card not rendered for chat id {}: {}", msg.chat.id, e);
+            bot.send_message(msg.chat.id, tr.card_preview_failed)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `calendar` command for the bot.
+/// This function renders the calendar of the given month, or of the current month if not
+/// specified, with the names of the people on their birthdays and sends it to the chat as a
+/// picture. The months which have already passed this year are shown for the next year.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `month` - The name or the number of the month.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_calendar_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    month: String,
+) -> ResponseResult<()> {
+    log::info!("Calendar command received from chat id {}", msg.chat.id);
+
+    let b_map = cfg.b_map.read().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let today = Utc::now().date_naive();
+    let month = if month.trim().is_empty() {
+        Some(today.month())
+    } else {
+        crate::utils::parse_month(&month)
+    };
+    let year = match month {
+        Some(month) if month < today.month() => today.year() + 1,
+        _ => today.year(),
+    };
+    let month = match month.and_then(|month| NaiveDate::from_ymd_opt(year, month, 1)) {
+        Some(month) => month,
+        None => {
+            bot.send_message(msg.chat.id, tr.invalid_month).await?;
+            return Ok(());
+        }
+    };
+
+    let month_name = tr.month_names[month.month0() as usize];
+    let birthdays = b_map
+        .get(&msg.chat.id)
+        .map(|(_, birthdays)| birthdays.in_month(month.month()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|birthday| birthday.kind.is_birthday())
+        .cloned()
+        .collect::<Vec<_>>();
+    drop(b_map);
+    if birthdays.is_empty() {
+        bot.send_message(msg.chat.id, fill(tr.month_empty, &[("month", &month_name)]))
+            .await?;
+        return Ok(());
+    }
+
+    let calendar = crate::card::render_blocking(move || {
+        let birthdays = birthdays.iter().collect::<Vec<_>>();
+        crate::calendar::render(&birthdays, month, today, lang)
+    })
+    .await;
+
+    match calendar {
+        Ok(calendar) => {
+            bot.send_photo(
+                msg.chat.id,
+                InputFile::memory(calendar).file_name("calendar.png"),
+            )
+            .caption(fill(
+                tr.calendar_caption,
+                &[("month", &month_name), ("year", &month.year())],
+            ))
+            .await?;
+        }
+        Err(e) => {
+            log::error!("Calendar not rendered for chat id {}: {}", msg.chat.id, e);
+            bot.send_message(msg.chat.id, tr.calendar_failed).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `collect` command for the bot.
+/// This function opens a gift money collection for the birthday with the given number in the list
+/// or the given name, sends the collection message with the buttons to mark the payment and to
+/// close the collection, and pins it.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number or the name of the birthday and the target amount.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_collect_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Collect command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let args = args.trim();
+    let (query, target) = match (
+        msg.from(),
+        args.rsplit_once(char::is_whitespace)
+            .map(|(query, target)| (query.trim(), target.parse::<u64>())),
+    ) {
+        (Some(_), Some((query, Ok(target)))) if !query.is_empty() && target > 0 => (query, target),
+        _ => {
+            bot.send_message(msg.chat.id, tr.collection_usage).await?;
+            return Ok(());
+        }
+    };
+
+    let found = match b_map.get(&msg.chat.id) {
+        Some((_, birthdays)) => match crate::utils::parse_birthday_index(query) {
+            Some(index) => birthdays.iter().nth(index).into_iter().collect::<Vec<_>>(),
+            None => birthdays
+                .find(query)
+                .into_iter()
+                .map(|(_, birthday)| birthday)
+                .collect(),
+        },
+        None => Vec::new(),
+    };
+    let birthday = match found.as_slice() {
+        [birthday] => (*birthday).clone(),
+        [] => {
+            bot.send_message(msg.chat.id, tr.collection_birthday_not_found)
+                .await?;
+            return Ok(());
+        }
+        _ => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.collection_ambiguous, &[("query", &query)]),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    if b_map
+        .collections(&msg.chat.id)
+        .iter()
+        .any(|collection| collection.name == birthday.name && collection.date == birthday.date)
+    {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.collection_exists, &[("name", &birthday.name)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let organizer = msg.from().unwrap();
+    let mut collection = Collection {
+        id: 0,
+        name: birthday.name,
+        date: birthday.date,
+        target,
+        organizer: organizer.id,
+        organizer_name: organizer.full_name(),
+        contributors: Vec::new(),
+        message_id: None,
+    };
+    collection.id = match b_map.add_collection(&msg.chat.id, collection.clone()) {
+        Ok(id) => id,
+        Err(err) => {
+            log::error!(
+                "Collection not added for chat id {}: {:?}",
+                msg.chat.id,
+                err
+            );
+            bot.send_message(msg.chat.id, tr.busy).await?;
+            return Ok(());
+        }
+    };
+
+    let send = |topic: Option<i32>| {
+        let mut request = bot
+            .send_message(msg.chat.id, collection.text(lang, false))
+            .reply_markup(collection.keyboard(lang));
+        if let Some(topic) = topic {
+            request = request.message_thread_id(topic);
+        }
+        request
+    };
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let mut result = send(settings.topic).await;
+    // Fall back to the General topic if the chosen topic is deleted or closed.
+    let topic_lost =
+        settings.topic.is_some() && matches!(&result, Err(e) if crate::utils::is_topic_error(e));
+    if topic_lost {
+        log::warn!("The topic of chat id {} is unavailable", msg.chat.id);
+        settings.topic = None;
+        if let Err(err) = b_map.update_settings(&msg.chat.id, settings) {
+            log::error!(
+                "Settings not updated for chat id {}: {:?}",
+                msg.chat.id,
+                err
+            );
+        }
+        result = send(None).await;
+    }
+    // The collection can't be closed without the buttons of its message, so it is forgotten if the
+    // message is not sent, and the administrator can open it again.
+    let sent = match result {
+        Ok(sent) => sent,
+        Err(e) => {
+            b_map.remove_collection(&msg.chat.id, collection.id);
+            return Err(e);
+        }
+    };
+    if topic_lost {
+        bot.send_message(msg.chat.id, tr.topic_lost).await?;
+    }
+    if let Some(in_collection) = b_map.collection_mut(&msg.chat.id, collection.id) {
+        in_collection.message_id = Some(sent.id);
+    }
+    log::info!(
+        "Collection {} opened for chat id {}",
+        collection.id,
+        msg.chat.id
+    );
+
+    // The bot may have no rights to pin messages, the collection works without pinning as well.
+    if let Err(e) = bot
+        .pin_chat_message(msg.chat.id, sent.id)
+        .disable_notification(true)
+        .await
+    {
+        log::warn!("Failed to pin the collection message: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Handles the `topic` command for the bot.
+/// This function makes the forum topic the command is sent in the topic for the reminders, digests
+/// and collections of the chat, and sends a message to the topic to confirm the change. Sent in
+/// the General topic, the command resets the choice.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_topic_command(bot: Bot, msg: Message, cfg: ConfigParameters) -> ResponseResult<()> {
+    log::info!("Topic command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let tr = settings.language.messages();
+
+    if !crate::utils::is_forum(&msg.chat) {
+        bot.send_message(msg.chat.id, tr.topic_not_forum).await?;
+        return Ok(());
+    }
+
+    let topic = crate::utils::get_topic(&msg);
+    settings.topic = topic;
+
+    let text = match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) if topic.is_some() => tr.topic_set,
+        Ok(_) => tr.topic_reset,
+        Err(_) => tr.busy,
+    };
+    let mut request = bot.send_message(msg.chat.id, text);
+    if let Some(topic) = topic {
+        request = request.message_thread_id(topic);
+    }
+    request.await?;
+
+    Ok(())
+}
+
+/// Handles the `event` command for the bot.
+/// This function schedules a one-off or recurring event for the chat and sends a message to the
+/// chat with the date of its next occurrence. The reminder about the event is sent on the day of
+/// the event together with the birthday reminders.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The recurrence rule and the title of the event separated by a comma.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_event_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Event command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    if args.trim().is_empty() {
+        bot.send_message(msg.chat.id, tr.event_usage).await?;
+        return Ok(());
+    }
+
+    // The reminders of today may have already been sent, so the events start from the next run.
+    let first_date = crate::tasks::first_reminder_date();
+    let event = match ScheduledEvent::parse(&args, first_date) {
+        Ok(event) => event,
+        Err(err) => {
+            log::warn!("Invalid event: {:?}", err);
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.invalid_event, &[("error", &err.describe(lang))]),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    if b_map.events(&msg.chat.id).len() >= schedule::MAX_EVENTS {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.events_too_many, &[("max", &schedule::MAX_EVENTS)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let next_date = event
+        .recurrence
+        .next_date(first_date)
+        .map(|date| date.format("%d.%m.%Y").to_string())
+        .unwrap_or_default();
+    match b_map.add_event(&msg.chat.id, event) {
+        Ok(id) => {
+            log::info!("Event {} scheduled for chat id {}", id, msg.chat.id);
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.event_added, &[("id", &id), ("date", &next_date)]),
+            )
+            .await?;
+        }
+        Err(err) => {
+            log::error!("Event not added for chat id {}: {:?}", msg.chat.id, err);
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `events` command for the bot.
+/// This function sends a message to the chat with the list of the scheduled events, their rules
+/// and the dates of their next occurrences.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_events_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Events command received from chat id {}", msg.chat.id);
+
+    let b_map = cfg.b_map.read().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let events = b_map.events(&msg.chat.id);
+    if events.is_empty() {
+        bot.send_message(msg.chat.id, tr.events_empty).await?;
+        return Ok(());
+    }
+
+    let today = Utc::now().date_naive();
+    let list = events
+        .iter()
+        .map(|event| {
+            let next_date = event
+                .recurrence
+                .next_date(today)
+                .map(|date| date.format("%d.%m.%Y").to_string())
+                .unwrap_or_default();
+            format!(
+                "{}. {} — {} ({})\n",
+                event.id,
+                event.title,
+                event.recurrence.describe(lang),
+                fill(tr.event_next, &[("date", &next_date)])
+            )
+        })
+        .collect::<String>();
+    bot.send_message(msg.chat.id, format!("{}\n{}", tr.events_header, list))
+        .await?;
+
+    Ok(())
+}
+
+/// Handles the `cancelevent` command for the bot.
+/// This function removes the scheduled event with the given number from the list of events and
+/// sends a message to the chat to confirm the removal.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `id` - The number of the event from the list of events.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_cancel_event_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    id: String,
+) -> ResponseResult<()> {
+    log::info!("CancelEvent command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let event = id
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .and_then(|id| b_map.remove_event(&msg.chat.id, id));
+    match event {
+        Some(event) => {
+            log::info!("Event {} cancelled for chat id {}", event.id, msg.chat.id);
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.event_cancelled, &[("title", &event.title)]),
+            )
+            .await?;
+        }
+        None => {
+            bot.send_message(msg.chat.id, tr.event_not_found).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `poll` command for the bot.
+/// This function attaches a poll with the given question and options to the birthday with the
+/// given number in the list and sends a message to the chat to confirm it. The poll is posted with
+/// the advance reminder and summarized the day before the birthday. Without arguments, the
+/// function sends the list of the polls of the chat and the instructions.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the birthday from the list, the question and the options separated by
+///   semicolons, e.g. "3 What shall we give?; A book; Flowers".
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_poll_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Poll command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let args = args.trim();
+    if args.is_empty() {
+        let polls = b_map.polls(&msg.chat.id);
+        let text = if polls.is_empty() {
+            tr.poll_usage.to_string()
+        } else {
+            let birthdays = b_map.get(&msg.chat.id).map(|(_, birthdays)| birthdays);
+            let list = polls
+                .iter()
+                .map(|poll| {
+                    let status = match poll.message_id {
+                        Some(_) => tr.poll_posted,
+                        None => tr.poll_waiting,
+                    };
+                    // Show the current name and date in case the linked person was changed.
+                    let (name, date) = birthdays
+                        .and_then(|birthdays| birthdays.iter().find(|b| poll.is_about(b)))
+                        .map_or((&poll.name, &poll.date), |birthday| {
+                            (&birthday.name, &birthday.date)
+                        });
+                    format!(
+                        "{}. {} ({}): {} — {}\n",
+                        poll.id, name, date, poll.question, status
+                    )
+                })
+                .collect::<String>();
+            format!("{}\n{}\n{}", tr.polls_header, list, tr.poll_usage)
+        };
+        bot.send_message(msg.chat.id, text).await?;
+        return Ok(());
+    }
+
+    // The poll and its results are posted in the chat, so they would spoil the surprise.
+    if settings.surprise_mode {
+        bot.send_message(msg.chat.id, tr.poll_surprise).await?;
+        return Ok(());
+    }
+
+    let (index, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+    let birthday = match (
+        crate::utils::parse_birthday_index(index),
+        b_map.get(&msg.chat.id),
+    ) {
+        (Some(index), Some((_, birthdays))) => birthdays.iter().nth(index).cloned(),
+        _ => None,
+    };
+    let birthday = match birthday {
+        Some(birthday) => birthday,
+        None => {
+            bot.send_message(msg.chat.id, tr.poll_birthday_not_found)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let (question, options) = match poll::parse(rest) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            log::warn!("Invalid poll: {:?}", err);
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    // The results are summarized the day before the birthday, so there must be time to vote.
+    let today = Utc::now().date_naive();
+    if birthday.days_until(today).is_none_or(|days| days <= 1) {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.poll_too_soon, &[("name", &birthday.name)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let polls = b_map.polls(&msg.chat.id);
+    if polls.iter().any(|poll| poll.is_about(&birthday)) {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.poll_exists, &[("name", &birthday.name)]),
+        )
+        .await?;
+        return Ok(());
+    }
+    if polls.len() >= poll::MAX_POLLS {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.polls_too_many, &[("max", &poll::MAX_POLLS)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let poll = CelebrationPoll {
+        id: 0,
+        name: birthday.name.clone(),
+        date: birthday.date,
+        user_id: birthday.user_id.filter(|_| birthday.kind.is_birthday()),
+        question,
+        options,
+        poll_id: None,
+        message_id: None,
+        votes: HashMap::new(),
+    };
+    match b_map.add_poll(&msg.chat.id, poll) {
+        Ok(id) => {
+            log::info!("Poll {} added for chat id {}", id, msg.chat.id);
+            let mut text = fill(tr.poll_added, &[("id", &id), ("name", &birthday.name)]);
+            if settings.advance_days < 2 {
+                text = format!("{}\n\n{}", text, tr.poll_needs_advance);
+            }
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Err(err) => {
+            log::error!("Poll not added for chat id {}: {:?}", msg.chat.id, err);
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `cancelpoll` command for the bot.
+/// This function removes the poll with the given number from the list of polls, stops it if it
+/// has been posted, and sends a message to the chat to confirm the removal.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `id` - The number of the poll from the list of polls.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_cancel_poll_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    id: String,
+) -> ResponseResult<()> {
+    log::info!("CancelPoll command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let poll = id
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .and_then(|id| b_map.remove_poll(&msg.chat.id, id));
+    match poll {
+        Some(poll) => {
+            log::info!("Poll {} cancelled for chat id {}", poll.id, msg.chat.id);
+            if let Some(message_id) = poll.message_id {
+                if let Err(e) = bot.stop_poll(msg.chat.id, message_id).await {
+                    log::warn!("Failed to stop the poll: {}", e);
+                }
+            }
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.poll_cancelled, &[("name", &poll.name)]),
+            )
+            .await?;
+        }
+        None => {
+            bot.send_message(msg.chat.id, tr.poll_not_found).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `tags` command for the bot.
+/// This function replaces the tags of the birthday with the given number in the list, or removes
+/// them if no tags are given, and sends a message to the chat to confirm the change.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the birthday from the list and the hashtags, e.g. "3 #backend".
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_tags_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Tags command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let (tags, index) = match crate::parser::parse_tags(&args) {
+        Ok((tags, index)) => match crate::utils::parse_birthday_index(index.trim()) {
+            Some(index) => (tags, index),
+            None => {
+                bot.send_message(msg.chat.id, tr.tags_usage).await?;
+                return Ok(());
+            }
+        },
+        Err(err) => {
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    let birthday = b_map
+        .get_mut(&msg.chat.id)
+        .and_then(|(_, birthdays)| birthdays.get_mut(index));
+    match birthday {
+        Some(birthday) => {
+            birthday.tags = tags;
+            let text = if birthday.tags.is_empty() {
+                fill(tr.tags_cleared, &[("name", &birthday.name)])
+            } else {
+                fill(
+                    tr.tags_updated,
+                    &[
+                        ("name", &birthday.name),
+                        ("tags", &birthday.tags_label().trim()),
+                    ],
+                )
+            };
+            log::info!("Tags updated for chat id {}", msg.chat.id);
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        None => {
+            bot.send_message(msg.chat.id, tr.birthday_not_found).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `tagrule` command for the bot.
+/// This function sets the rule of delivering the reminders about the people with the given tag and
+/// sends a message to the chat to confirm the change. Without arguments, the current rules are
+/// shown.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The hashtag and the name of the rule, e.g. "#management advance".
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_tag_rule_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("TagRule command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&msg.chat.id);
+    let lang = settings.language;
+    let tr = lang.messages();
+
+    let rules = DeliveryRule::ALL
+        .iter()
+        .map(|rule| format!("{} — {}", rule.keyword(), rule.describe(lang)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let usage = fill(tr.tag_rule_usage, &[("rules", &rules)]);
+
+    if args.trim().is_empty() {
+        let mut tag_rules = settings.tag_rules.iter().collect::<Vec<_>>();
+        tag_rules.sort();
+        let text = if tag_rules.is_empty() {
+            usage
+        } else {
+            let list = tag_rules
+                .iter()
+                .map(|(tag, rule)| format!("#{} — {}\n", tag, rule.describe(lang)))
+                .collect::<String>();
+            format!("{}\n{}\n{}", tr.tag_rules_header, list, usage)
+        };
+        bot.send_message(msg.chat.id, text).await?;
+        return Ok(());
+    }
+
+    let (tag, rule) = match crate::parser::parse_tags(&args) {
+        Ok((tags, rule)) => match (tags.as_slice(), DeliveryRule::parse(&rule)) {
+            ([tag], Some(rule)) => (tag.clone(), rule),
+            _ => {
+                bot.send_message(msg.chat.id, usage).await?;
+                return Ok(());
+            }
+        },
+        Err(err) => {
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    if rule == DeliveryRule::All {
+        settings.tag_rules.remove(&tag);
+    } else {
+        settings.tag_rules.insert(tag.clone(), rule);
+    }
+
+    match b_map.update_settings(&msg.chat.id, settings) {
+        Ok(_) => {
+            bot.send_message(
+                msg.chat.id,
+                fill(
+                    tr.tag_rule_set,
+                    &[("tag", &tag), ("rule", &rule.describe(lang))],
+                ),
+            )
+            .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `notes` command for the bot.
+/// This function sends the notes and the gift history of the birthday with the given number in the
+/// list. In groups, they are sent to the administrator in private messages, so the members of the
+/// chat don't see them.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `index` - The number of the birthday from the list.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_notes_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    index: String,
+) -> ResponseResult<()> {
+    log::info!("Notes command received from chat id {}", msg.chat.id);
+
+    let b_map = cfg.b_map.read().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let birthday = crate::utils::parse_birthday_index(index.trim()).and_then(|index| {
+        b_map
+            .get(&msg.chat.id)
+            .and_then(|(_, birthdays)| birthdays.iter().nth(index))
+    });
+    let (birthday, user) = match (birthday, msg.from()) {
+        (Some(birthday), Some(user)) => (birthday, user),
+        _ => {
+            bot.send_message(msg.chat.id, tr.notes_usage).await?;
+            return Ok(());
+        }
+    };
+
+    let notes = if birthday.notes.is_empty() {
+        format!("{}\n", tr.notes_empty)
+    } else {
+        birthday
+            .notes
+            .iter()
+            .map(|note| format!("• {}\n", note))
+            .collect::<String>()
+    };
+    let mut gifts = birthday.gifts.iter().collect::<Vec<_>>();
+    gifts.sort_by_key(|gift| gift.year);
+    let gifts = if gifts.is_empty() {
+        tr.gifts_empty.to_string()
+    } else {
+        gifts
+            .iter()
+            .map(|gift| format!("{} — {}\n", gift.year, gift.description))
+            .collect::<String>()
+    };
+    let text = format!(
+        "{}\n{}\n{}\n{}",
+        fill(tr.notes_header, &[("name", &birthday.name)]),
+        notes,
+        tr.gifts_header,
+        gifts
+    );
+
+    if msg.chat.is_private() {
+        bot.send_message(msg.chat.id, text).await?;
+        return Ok(());
+    }
+
+    match bot.send_message(ChatId(user.id.0 as i64), text).await {
+        Ok(_) => {
+            bot.send_message(msg.chat.id, tr.notes_sent_privately)
+                .await?;
+        }
+        Err(e) => {
+            log::warn!("Notes not sent to user {}: {}", user.id, e);
+            bot.send_message(msg.chat.id, tr.notes_private_failed)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `note` command for the bot.
+/// This function appends a note to the birthday with the given number in the list. In groups, the
+/// command message is deleted, so the note doesn't stay in the chat. If the bot is not allowed to
+/// delete it, the note is not saved.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the birthday from the list and the text of the note.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_note_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Note command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let (index, note) = match args.trim().split_once(char::is_whitespace) {
+        Some((index, note)) => (crate::utils::parse_birthday_index(index), note.trim()),
+        None => (None, ""),
+    };
+    if note.chars().count() > NOTE_MAX_LEN {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.note_too_long, &[("max", &NOTE_MAX_LEN)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let birthday = index.and_then(|index| {
+        b_map
+            .get_mut(&msg.chat.id)
+            .and_then(|(_, birthdays)| birthdays.get_mut(index))
+    });
+    match birthday {
+        Some(birthday) if !note.is_empty() => {
+            if birthday.notes.len() >= MAX_NOTES {
+                bot.send_message(msg.chat.id, fill(tr.notes_too_many, &[("max", &MAX_NOTES)]))
+                    .await?;
+                return Ok(());
+            }
+            if !hide_command(&bot, &msg).await {
+                bot.send_message(msg.chat.id, tr.private_not_hidden).await?;
+                return Ok(());
+            }
+            birthday.notes.push(note.to_string());
+            log::info!("Note added for chat id {}", msg.chat.id);
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.note_added, &[("name", &birthday.name)]),
+            )
+            .await?;
+        }
+        _ => {
+            bot.send_message(msg.chat.id, tr.note_usage).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `gift` command for the bot.
+/// This function appends a gift to the history of the birthday with the given number in the list.
+/// The year of the gift is optional and defaults to the current year. In groups, the command
+/// message is deleted, so the gift stays a secret. If the bot is not allowed to delete it, the gift
+/// is not saved.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the birthday from the list, the optional year and the description of
+///   the gift, e.g. "3 2025 a book".
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn handle_gift_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Gift command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let (index, rest) = match args.trim().split_once(char::is_whitespace) {
+        Some((index, rest)) => (crate::utils::parse_birthday_index(index), rest.trim()),
+        None => (None, ""),
+    };
+    let current_year = Utc::now().year();
+    let (year, description) = match rest.split_once(char::is_whitespace) {
+        Some((year, description)) if year.len() == 4 => match year.parse::<i32>() {
+            Ok(year) if year <= current_year => (year, description.trim()),
+            _ => (current_year, rest),
+        },
+        _ => (current_year, rest),
+    };
+    if description.chars().count() > NOTE_MAX_LEN {
+        bot.send_message(
+            msg.chat.id,
+            fill(tr.note_too_long, &[("max", &NOTE_MAX_LEN)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let birthday = index.and_then(|index| {
+        b_map
+            .get_mut(&msg.chat.id)
+            .and_then(|(_, birthdays)| birthdays.get_mut(index))
+    });
+    match birthday {
+        Some(birthday) if !description.is_empty() => {
+            if birthday.gifts.len() >= MAX_GIFTS {
+                bot.send_message(msg.chat.id, fill(tr.gifts_too_many, &[("max", &MAX_GIFTS)]))
+                    .await?;
+                return Ok(());
+            }
+            if !hide_command(&bot, &msg).await {
+                bot.send_message(msg.chat.id, tr.private_not_hidden).await?;
+                return Ok(());
+            }
+            birthday.gifts.push(Gift {
+                year,
+                description: description.to_string(),
+            });
+            log::info!("Gift added for chat id {}", msg.chat.id);
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.gift_added, &[("name", &birthday.name), ("year", &year)]),
+            )
+            .await?;
+        }
+        _ => {
+            bot.send_message(msg.chat.id, tr.gift_usage).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Deletes the command message in groups, so the private details it contains don't stay in the
+/// chat.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message with the command.
+///
+/// # Returns
+///
+/// `true` if the message is deleted or sent in a private chat, `false` if the bot lacks the
+/// permission to delete it.
+async fn hide_command(bot: &Bot, msg: &Message) -> bool {
+    if msg.chat.is_private() {
+        return true;
+    }
+    match bot.delete_message(msg.chat.id, msg.id).await {
+        Ok(_) => true,
+        Err(e) => {
+            log::warn!(
+                "Command message not deleted in chat id {}: {}",
+                msg.chat.id,
+                e
+            );
+            false
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use teloxide::payloads::{
+    AnswerCallbackQuerySetters, EditMessageTextSetters, SendMessageSetters, UnpinChatMessageSetters,
+};
+use teloxide::prelude::{CallbackQuery, Message, Requester, ResponseResult, UserId};
+use teloxide::types::PollAnswer;
+use teloxide::Bot;
+
+use crate::collection::CollectionAction;
+use crate::i18n::fill;
+use crate::wishlist::{self, WishlistAction};
+use crate::ConfigParameters;
+
+/// Handles callback queries from the inline buttons of the bot messages.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `q` - The callback query.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the query handling.
+pub async fn callback_query_handler(
+    bot: Bot,
+    q: CallbackQuery,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    let (data, message) = match (q.data.as_deref(), q.message.as_ref()) {
+        (Some(data), Some(message)) => (data, message),
+        _ => return Ok(()),
+    };
+
+    if let Some((action, id)) = CollectionAction::parse(data) {
+        handle_collection_callback(&bot, &q, message, action, id, &cfg).await?;
+    } else if let Some((action, owner)) = WishlistAction::parse(data) {
+        handle_wishlist_callback(&bot, &q, message, action, owner, &cfg).await?;
+    } else {
+        log::warn!("Unknown callback data: {}", data);
+        bot.answer_callback_query(q.id.clone()).await?;
+    }
+
+    Ok(())
+}
+
+/// Handles the answers to the celebration polls posted by the bot.
+/// The answer is recorded in the tracked poll, so the results can be summarized when the poll is
+/// stopped. Answers to the polls which are not tracked are ignored.
+///
+/// # Arguments
+///
+/// * `answer` - The answer of the member.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the answer handling.
+pub async fn poll_answer_handler(answer: PollAnswer, cfg: ConfigParameters) -> ResponseResult<()> {
+    let mut b_map = cfg.b_map.write().await;
+    if let Some(poll) = b_map.poll_by_telegram_id_mut(&answer.poll_id) {
+        log::info!(
+            "User id {} answered the poll {} about {}",
+            answer.user.id,
+            answer.poll_id,
+            poll.name
+        );
+        poll.vote(answer.user.id, &answer.option_ids);
+    }
+
+    Ok(())
+}
+
+/// Handles the buttons of the gift collection message.
+/// The `Paid` button marks or unmarks the member as a contributor and updates the message. The
+/// `Close` button closes the collection if it is pressed by the organizer, an administrator of the
+/// chat or the maintainer, and unpins the message.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `q` - The callback query.
+/// * `message` - The collection message.
+/// * `action` - The action of the button.
+/// * `id` - The ID of the collection.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the query handling.
+async fn handle_collection_callback(
+    bot: &Bot,
+    q: &CallbackQuery,
+    message: &Message,
+    action: CollectionAction,
+    id: u32,
+    cfg: &ConfigParameters,
+) -> ResponseResult<()> {
+    let chat_id = message.chat.id;
+    log::info!(
+        "Collection {:?} button pressed for collection {} in chat id {}",
+        action,
+        id,
+        chat_id
+    );
+
+    let (organizer, lang) = {
+        let b_map = cfg.b_map.read().await;
+        let organizer = b_map
+            .collections(&chat_id)
+            .iter()
+            .find(|collection| collection.id == id)
+            .map(|collection| collection.organizer);
+        (organizer, b_map.get_settings(&chat_id).language)
+    };
+    let tr = lang.messages();
+
+    let organizer = match organizer {
+        Some(organizer) => organizer,
+        None => {
+            bot.answer_callback_query(q.id.clone())
+                .text(tr.collection_not_found)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    match action {
+        CollectionAction::Paid => {
+            let mut b_map = cfg.b_map.write().await;
+            let (is_paid, text, keyboard) = match b_map.collection_mut(&chat_id, id) {
+                Some(collection) => (
+                    collection.toggle_contributor(&q.from),
+                    collection.text(lang, false),
+                    collection.keyboard(lang),
+                ),
+                None => {
+                    bot.answer_callback_query(q.id.clone())
+                        .text(tr.collection_not_found)
+                        .await?;
+                    return Ok(());
+                }
+            };
+            drop(b_map);
+
+            bot.edit_message_text(chat_id, message.id, text)
+                .reply_markup(keyboard)
+                .await?;
+            bot.answer_callback_query(q.id.clone())
+                .text(if is_paid {
+                    tr.collection_paid
+                } else {
+                    tr.collection_unpaid
+                })
+                .await?;
+        }
+        CollectionAction::Close => {
+            let is_allowed = q.from.id == organizer
+                || q.from.id == cfg.bot_maintainer
+                || crate::utils::is_admin(bot, chat_id, q.from.id)
+                    .await
+                    .unwrap_or_default();
+            if !is_allowed {
+                bot.answer_callback_query(q.id.clone())
+                    .text(tr.collection_close_forbidden)
+                    .show_alert(true)
+                    .await?;
+                return Ok(());
+            }
+
+            let collection = cfg.b_map.write().await.remove_collection(&chat_id, id);
+            let collection = match collection {
+                Some(collection) => collection,
+                None => {
+                    bot.answer_callback_query(q.id.clone())
+                        .text(tr.collection_not_found)
+                        .await?;
+                    return Ok(());
+                }
+            };
+            log::info!("Collection {} closed for chat id {}", id, chat_id);
+
+            // Editing the text without the keyboard removes the buttons.
+            bot.edit_message_text(chat_id, message.id, collection.text(lang, true))
+                .await?;
+            if let Err(e) = bot.unpin_chat_message(chat_id).message_id(message.id).await {
+                log::warn!("Failed to unpin the collection message: {}", e);
+            }
+            bot.answer_callback_query(q.id.clone())
+                .text(tr.collection_closed)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the buttons of the wishlist messages.
+/// The `Show` button sends the wishlist of the person to the chat. The `Claim` button marks the
+/// item as chosen by the member, or cancels the choice if the same member presses it again, and
+/// updates the message, so the chosen item is hidden from the list.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `q` - The callback query.
+/// * `message` - The message with the button.
+/// * `action` - The action of the button.
+/// * `owner` - The Telegram user ID of the person whose wishlist it is.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the query handling.
+async fn handle_wishlist_callback(
+    bot: &Bot,
+    q: &CallbackQuery,
+    message: &Message,
+    action: WishlistAction,
+    owner: UserId,
+    cfg: &ConfigParameters,
+) -> ResponseResult<()> {
+    let chat_id = message.chat.id;
+    log::info!(
+        "Wishlist {:?} button pressed for user {} in chat id {}",
+        action,
+        owner,
+        chat_id
+    );
+
+    let (name, mut items, lang) = {
+        let b_map = cfg.b_map.read().await;
+        (
+            b_map
+                .find_by_user(owner)
+                .map(|birthday| birthday.name.clone()),
+            b_map.wishlist(&owner).to_vec(),
+            b_map.get_settings(&chat_id).language,
+        )
+    };
+    let tr = lang.messages();
+
+    let name = match name {
+        Some(name) => name,
+        None => {
+            bot.answer_callback_query(q.id.clone())
+                .text(tr.wishlist_item_not_found)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let id = match action {
+        WishlistAction::Show => {
+            bot.send_message(chat_id, wishlist::text(&name, &items, lang))
+                .reply_markup(wishlist::keyboard(owner, &items, lang))
+                .await?;
+            bot.answer_callback_query(q.id.clone()).await?;
+            return Ok(());
+        }
+        WishlistAction::Claim(id) => id,
+    };
+
+    if q.from.id == owner {
+        bot.answer_callback_query(q.id.clone())
+            .text(tr.wishlist_own_claim)
+            .show_alert(true)
+            .await?;
+        return Ok(());
+    }
+
+    let item = match items.iter_mut().find(|item| item.id == id) {
+        Some(item) => item,
+        None => {
+            bot.answer_callback_query(q.id.clone())
+                .text(tr.wishlist_item_not_found)
+                .await?;
+            return Ok(());
+        }
+    };
+    let answer = match item.claimed_by {
+        None => {
+            item.claimed_by = Some(q.from.id);
+            fill(tr.wishlist_claimed, &[("item", &item.text)])
+        }
+        Some(user_id) if user_id == q.from.id => {
+            item.claimed_by = None;
+            fill(tr.wishlist_unclaimed, &[("item", &item.text)])
+        }
+        Some(_) => {
+            bot.answer_callback_query(q.id.clone())
+                .text(tr.wishlist_already_claimed)
+                .show_alert(true)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let result = cfg
+        .b_map
+        .write()
+        .await
+        .update_wishlist(&owner, items.clone());
+    if let Err(err) = result {
+        log::error!("Wishlist of user {} not updated: {:?}", owner, err);
+        bot.answer_callback_query(q.id.clone())
+            .text(tr.busy)
+            .await?;
+        return Ok(());
+    }
+
+    bot.edit_message_text(chat_id, message.id, wishlist::text(&name, &items, lang))
+        .reply_markup(wishlist::keyboard(owner, &items, lang))
+        .await?;
+    bot.answer_callback_query(q.id.clone())
+        .text(answer)
+        .show_alert(true)
+        .await?;
+
+    Ok(())
+}
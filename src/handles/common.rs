@@ -1,11 +1,17 @@
 use async_tempfile::TempFile;
 use teloxide::net::Download;
 use teloxide::prelude::{Message, Request, Requester, ResponseResult};
-use teloxide::types::{ChatId, Document};
+use teloxide::types::{ChatId, Document, MessageKind, User};
 use teloxide::Bot;
 
-use crate::handles::BUSY_MSG;
-use crate::{ConfigParameters, State};
+use crate::i18n::fill;
+use crate::{Birthday, ConfigParameters, State};
+
+/// The maximum number of birthdays in one text message.
+const MAX_BIRTHDAYS_PER_MESSAGE: usize = 50;
+
+/// The maximum length of a failed line quoted in the report in characters.
+const LINE_EXCERPT_LEN: usize = 40;
 
 /// Handles common commands for the bot.
 /// This function triggers for all messages in chats and depending on the bot state, it processes
@@ -45,7 +51,7 @@ pub async fn common_commands_handler(
         }
         State::WaitingBirthday => {
             if let Some(text) = msg.text() {
-                add_handler(text, bot, chat_id, cfg).await?
+                add_handler(text, linked_user(&msg), bot, chat_id, cfg).await?
             }
         }
         State::WaitingRemoving => {
@@ -59,42 +65,250 @@ pub async fn common_commands_handler(
     Ok(())
 }
 
+/// Returns the Telegram user the birthday in the message refers to.
+/// The user is taken from the origin of a forwarded message, or from the author (or the origin) of
+/// the message being replied to. Bots are never linked.
+///
+/// # Arguments
+///
+/// * `msg` - The message containing the birthday.
+///
+/// # Returns
+///
+/// Some user if the message refers to one, otherwise None.
+fn linked_user(msg: &Message) -> Option<&User> {
+    msg.forward_from_user()
+        .filter(|user| !user.is_bot)
+        .or_else(|| replied_user(msg))
+}
+
+/// Returns the Telegram user whose message is being replied to.
+/// If the replied message is forwarded, the origin of the forwarded message is returned.
+/// Bots are never returned. In forum topics, every message replies to the service message about the
+/// creation of the topic, so such a reply does not refer to its author.
+///
+/// # Arguments
+///
+/// * `msg` - The message replying to another message.
+///
+/// # Returns
+///
+/// Some user if the message is a reply to a message of a user, otherwise None.
+pub fn replied_user(msg: &Message) -> Option<&User> {
+    msg.reply_to_message()
+        .filter(|reply| !matches!(reply.kind, MessageKind::ForumTopicCreated(_)))
+        .and_then(|reply| reply.forward_from_user().or(reply.from()))
+        .filter(|user| !user.is_bot)
+}
+
 /// Handles text messages for the bot.
 /// This function processes the received text as a birthday and updates the bot state accordingly
-/// if the input is valid.
+/// if the input is valid. If the message refers to a Telegram user, the birthday is linked to them.
+/// If the text consists of several lines, each line is processed as a separate birthday.
 ///
 /// # Arguments
 ///
 /// * `text` - The reference to the received text.
+/// * `user` - The Telegram user the birthday refers to, if any.
 /// * `bot` - The bot instance.
-/// * `msg` - The message containing the text.
+/// * `chat_id` - The chat ID.
+/// * `cfg` - Configuration parameters for the bot.
 ///
 /// # Returns
 ///
 /// A `ResponseResult` indicating the success or failure of the command.
 pub async fn add_handler(
     text: &str,
+    user: Option<&User>,
     bot: Bot,
     chat_id: ChatId,
     cfg: ConfigParameters,
 ) -> ResponseResult<()> {
     log::info!("Birthday received from chat id {}", chat_id);
 
-    if let Some(birthday) = crate::utils::parse_birthday_info(text) {
+    let lines = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    if lines.len() > 1 {
+        return add_lines_handler(&lines, bot, chat_id, cfg).await;
+    }
+
+    let settings = cfg.b_map.read().await.get_settings(&chat_id);
+
+    match crate::parser::parse_birthday(text, settings.date_order) {
+        Ok(mut birthday) => {
+            if let Some(user) = user {
+                birthday.user_id = Some(user.id);
+                if birthday.username.is_empty() {
+                    if let Some(username) = &user.username {
+                        birthday.username = format!("@{}", username);
+                    }
+                }
+            }
+
+            add_birthday(birthday, bot, chat_id, cfg).await?;
+        }
+        Err(err) => {
+            log::warn!("Invalid input format: {:?}", err);
+            let tr = settings.language.messages();
+            bot.send_message(
+                chat_id,
+                fill(
+                    tr.invalid_input,
+                    &[("error", &err.describe(settings.language))],
+                ),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles text messages with several birthdays for the bot.
+/// This function processes each line as a separate birthday, adds the valid ones to the list under
+/// a single lock and sends a message to the chat with the result for each line and a summary.
+/// The failed lines are quoted shortly, and a long report is split into several messages.
+///
+/// # Arguments
+///
+/// * `lines` - The non-empty lines of the received text.
+/// * `bot` - The bot instance.
+/// * `chat_id` - The chat ID.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+async fn add_lines_handler(
+    lines: &[&str],
+    bot: Bot,
+    chat_id: ChatId,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    if lines.len() > MAX_BIRTHDAYS_PER_MESSAGE {
+        log::warn!("Too many birthdays in one message: {}", lines.len());
+        let tr = cfg
+            .b_map
+            .read()
+            .await
+            .get_settings(&chat_id)
+            .language
+            .messages();
+        bot.send_message(
+            chat_id,
+            fill(tr.too_many_lines, &[("max", &MAX_BIRTHDAYS_PER_MESSAGE)]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let report = {
         let mut b_map = cfg.b_map.write().await;
+        let settings = b_map.get_settings(&chat_id);
+        let tr = settings.language.messages();
 
-        if let Err(err) = b_map.update_birthdays(&chat_id, birthday) {
-            log::error!("Birthday not added for chat id {}: {:?}", chat_id, err);
-            bot.send_message(chat_id, BUSY_MSG).await?;
-        } else {
-            log::info!("Birthday added for chat id {}", chat_id);
-            bot.send_message(chat_id, "День рождения успешно добавлен! 🎉")
-                .await?;
+        let mut report = String::new();
+        let mut added = 0;
+        for (idx, line) in lines.iter().enumerate() {
+            let index = idx + 1;
+            let mut excerpt = line.chars().take(LINE_EXCERPT_LEN).collect::<String>();
+            if line.chars().count() > LINE_EXCERPT_LEN {
+                excerpt += "…";
+            }
+            let result = match crate::parser::parse_birthday(line, settings.date_order) {
+                Ok(birthday) => {
+                    let text = fill(
+                        tr.line_added,
+                        &[
+                            ("index", &index),
+                            ("name", &birthday.name),
+                            ("date", &birthday.date),
+                        ],
+                    );
+                    match b_map.update_birthdays(&chat_id, birthday) {
+                        Ok(_) => {
+                            added += 1;
+                            text
+                        }
+                        Err(err) => {
+                            log::error!("Birthday not added for chat id {}: {:?}", chat_id, err);
+                            fill(
+                                tr.line_failed,
+                                &[("index", &index), ("line", &excerpt), ("error", &tr.busy)],
+                            )
+                        }
+                    }
+                }
+                Err(err) => fill(
+                    tr.line_failed,
+                    &[
+                        ("index", &index),
+                        ("line", &excerpt),
+                        ("error", &err.describe(settings.language)),
+                    ],
+                ),
+            };
+            report += format!("{}\n", result).as_str();
         }
+
+        log::info!(
+            "{} of {} birthdays added for chat id {}",
+            added,
+            lines.len(),
+            chat_id
+        );
+        let summary = fill(
+            tr.lines_summary,
+            &[("added", &added), ("total", &lines.len())],
+        );
+        format!("{}\n{}", report, summary)
+    };
+
+    for part in crate::utils::split_message(&report) {
+        bot.send_message(chat_id, part).await?;
+    }
+
+    Ok(())
+}
+
+/// Adds the birthday to the list of the chat.
+/// This function validates the date of the birthday and adds it to the list, sending a message to
+/// the chat with the result.
+///
+/// # Arguments
+///
+/// * `birthday` - The birthday to add.
+/// * `bot` - The bot instance.
+/// * `chat_id` - The chat ID.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn add_birthday(
+    birthday: Birthday,
+    bot: Bot,
+    chat_id: ChatId,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    let mut b_map = cfg.b_map.write().await;
+    let tr = b_map.get_settings(&chat_id).language.messages();
+
+    if birthday.day_month().is_none() {
+        log::warn!("Invalid birthday date {}", birthday.date);
+        bot.send_message(chat_id, tr.invalid_format).await?;
+        return Ok(());
+    }
+
+    if let Err(err) = b_map.update_birthdays(&chat_id, birthday) {
+        log::error!("Birthday not added for chat id {}: {:?}", chat_id, err);
+        bot.send_message(chat_id, tr.busy).await?;
     } else {
-        log::warn!("Invalid input format");
-        bot.send_message(chat_id, "Неверный формат ввода 😔 Попробуйте ещё раз")
-            .await?;
+        log::info!("Birthday added for chat id {}", chat_id);
+        bot.send_message(chat_id, tr.birthday_added).await?;
     }
 
     Ok(())
@@ -123,6 +337,7 @@ pub async fn document_handler(
     log::info!("Document received from chat id {}", chat_id);
 
     let mut b_map = cfg.b_map.write().await;
+    let tr = b_map.get_settings(&chat_id).language.messages();
 
     let file = doc.file.clone();
     log::info!("Downloading file {} from chat id {}", file.id, chat_id);
@@ -137,20 +352,14 @@ pub async fn document_handler(
         Ok(birthdays) => {
             if let Err(err) = b_map.extend_birthdays(&chat_id, birthdays) {
                 log::error!("Birthdays not added for chat id {}: {:?}", chat_id, err);
-                bot.send_message(chat_id, BUSY_MSG).await?;
+                bot.send_message(chat_id, tr.busy).await?;
             } else {
-                bot.send_message(chat_id, "Дни рождения успешно загружены! 🎉")
-                    .await?;
+                bot.send_message(chat_id, tr.birthdays_loaded).await?;
             }
         }
         Err(e) => {
             log::error!("Failed to parse the file content: {}", e);
-            bot.send_message(
-                chat_id,
-                "К сожалению, отправленный файл не корректный или содержит ошибки😔 \
-                    Проверьте его и отправьте ещё раз",
-            )
-            .await?;
+            bot.send_message(chat_id, tr.invalid_file).await?;
         }
     }
 
@@ -179,16 +388,17 @@ pub async fn remove_handler(
 ) -> ResponseResult<()> {
     log::info!("Birthday index received from chat id {}", chat_id);
 
-    if let Some(index) = crate::utils::parse_birthday_index(text) {
-        let mut b_map = cfg.b_map.write().await;
+    let mut b_map = cfg.b_map.write().await;
+    let tr = b_map.get_settings(&chat_id).language.messages();
 
+    if let Some(index) = crate::utils::parse_birthday_index(text) {
         if let Some(birthday) = b_map.remove_birthday(&chat_id, index) {
             log::info!("Birthday {:?} removed for chat id {}", birthday, chat_id);
             bot.send_message(
                 chat_id,
-                format!(
-                    "День рождение, Имя: {}, Дата: {} успешно удалён!",
-                    birthday.name, birthday.date
+                fill(
+                    tr.birthday_removed,
+                    &[("name", &birthday.name), ("date", &birthday.date)],
                 ),
             )
             .await?;
@@ -198,16 +408,11 @@ pub async fn remove_handler(
                 index,
                 chat_id
             );
-            bot.send_message(
-                chat_id,
-                "День рождение не найден по указанному индексу 😔 Попробуйте ещё раз",
-            )
-            .await?;
+            bot.send_message(chat_id, tr.birthday_not_found).await?;
         }
     } else {
         log::warn!("Invalid input format");
-        bot.send_message(chat_id, "Неверный формат ввода 😔 Попробуйте ещё раз")
-            .await?;
+        bot.send_message(chat_id, tr.invalid_format).await?;
     }
 
     Ok(())
@@ -72,6 +72,18 @@ async fn handle_status_command(
         "Health Check Task (Неактивна) 🔴\n"
     };
 
+    reply_text += if cfg.task_manager.is_weekly_digest_active() {
+        "Weekly Digest Task (Активна) 🟢\n"
+    } else {
+        "Weekly Digest Task (Неактивна) 🔴\n"
+    };
+
+    reply_text += if cfg.task_manager.is_monthly_digest_active() {
+        "Monthly Digest Task (Активна) 🟢\n"
+    } else {
+        "Monthly Digest Task (Неактивна) 🔴\n"
+    };
+
     reply_text += format!(
         "\nУтилизация Birthday Map в байтах: {} (лимит {})\n\n",
         cfg.b_map.read().await.estimate_size(),
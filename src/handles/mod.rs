@@ -1,35 +1,26 @@
+use teloxide::payloads::SetMyCommandsSetters;
 use teloxide::prelude::{Message, Requester, ResponseResult, UserId};
-use teloxide::types::InputFile;
-use teloxide::utils::command::BotCommands;
+use teloxide::types::{BotCommand, BotCommandScope, InputFile, Me};
+use teloxide::utils::command::{BotCommands, CommandDescription, CommandDescriptions};
 use teloxide::Bot;
 
+use crate::i18n::fill;
+use crate::{ConfigParameters, Language};
+
 mod admin;
+mod callback;
 mod common;
 mod maintainer;
+mod public;
 
 pub use admin::admin_commands_handler;
+pub use callback::{callback_query_handler, poll_answer_handler};
 pub use common::common_commands_handler;
 pub use maintainer::maintainer_commands_handler;
 
 /// The file path for the sample JSON birthdays file.
 const SAMPLE_JSON_FILE_PATH: &str = "sample.json";
 
-/// The greetings message for the bot.
-const GREETINGS_MSG: &str =
-    "Привет! Этот бот создан для тех, кто постоянно забывает про дни рождения😁\n
-С помощью него вы никогда не забудете поздравить своих друзей, коллег по работе или родственников.\n
-Для более подробной информации по настройке используйте команду /help.";
-
-/// The messages to send when checking the control requested.
-const CREATOR_MESSAGE: &str = "Вы мой создатель!🙏";
-const ADMIN_INTERACTION_PREFIX: &str = "Вы можете взаимодействовать со мной ";
-const NO_INTERACTION_PREFIX: &str = "К сожалению, вы не можете взаимодействовать со мной ";
-
-/// The message to send when the bot is busy (limit of birthdays reached).
-const BUSY_MSG: &str =
-    "К сожалению, в данный момент я не могу принимать новые запросы из-за высокой нагрузки 😞 \
-Попробуйте повторить запрос позже";
-
 /// Enum defining maintainer commands for the bot.
 #[derive(BotCommands, Clone)]
 #[command(rename_rule = "lowercase")]
@@ -44,8 +35,11 @@ pub enum MaintainerCommands {
 #[derive(BotCommands, Clone)]
 #[command(rename_rule = "lowercase")]
 pub enum AdminCommands {
-    #[command(description = "Включает режим добавления дней рождений")]
-    Add,
+    #[command(
+        description = "Включает режим добавления дней рождений. Ответьте /add ДД-ММ на сообщение участника, \
+        чтобы добавить его день рождения"
+    )]
+    Add(String),
     #[command(
         description = "Включает режим добавления нескольких дней рождений в список используя JSON файл"
     )]
@@ -58,18 +52,105 @@ pub enum AdminCommands {
     Active,
     #[command(description = "Отключает уведомления о днях рождениях от меня")]
     Disable,
-    #[command(description = "Отображает список дней рождений")]
-    List,
+    #[command(
+        description = "Отображает список дней рождений и других событий, например /list work или /list #backend"
+    )]
+    List(String),
+    #[command(
+        description = "Присылает события в JSON файле для загрузки через /addmany, например /export #backend"
+    )]
+    Export(String),
+    #[command(description = "Скрывает или открывает список дней рождений для участников")]
+    Privacy,
+    #[command(
+        description = "Разрешает или запрещает участникам самостоятельно добавлять свои дни рождения"
+    )]
+    SelfRegistration,
+    #[command(description = "Переключает порядок дня и месяца в датах вида 05.03 или 5/3")]
+    DateOrder,
+    #[command(description = "Меняет мой язык, например /language en")]
+    Language(String),
+    #[command(
+        description = "Задаёт, за сколько дней напоминать о днях рождения, например /advance 3"
+    )]
+    Advance(String),
+    #[command(
+        description = "Показывает или меняет шаблоны поздравлений, например /greeting С днём рождения, {mention}!"
+    )]
+    Greeting(String),
+    #[command(description = "Показывает или меняет шаблоны напоминаний заранее")]
+    AdvanceGreeting(String),
+    #[command(description = "Объединяет напоминания одного дня в одно сообщение или разделяет их")]
+    Combine,
+    #[command(
+        description = "Включает или отключает сводку дней рождений на неделю или месяц, например /digest weekly"
+    )]
+    Digest(String),
+    #[command(
+        description = "Включает или отключает режим сюрприза: напоминания заранее получают только подписчики"
+    )]
+    Surprise,
+    #[command(
+        description = "Включает или отключает поздравления с именинами по православному календарю"
+    )]
+    NameDays,
+    #[command(
+        description = "Открывает сбор денег на подарок, например /collect 3 5000, где 3 — номер из /list"
+    )]
+    Collect(String),
+    #[command(
+        description = "Добавляет разовое или повторяющееся событие, например /event каждую первую пятницу, Обед с командой"
+    )]
+    Event(String),
+    #[command(description = "Отображает список запланированных событий")]
+    Events,
+    #[command(description = "Отменяет запланированное событие, например /cancelevent 2")]
+    CancelEvent(String),
+    #[command(
+        description = "Задаёт теги дня рождения, например /tags 3 #backend, где 3 — номер из /list"
+    )]
+    Tags(String),
+    #[command(
+        description = "Задаёт правило напоминаний для тега, например /tagrule #management advance"
+    )]
+    TagRule(String),
+    #[command(
+        description = "Присылает в личные сообщения заметки и историю подарков, например /notes 3"
+    )]
+    Notes(String),
+    #[command(description = "Добавляет заметку о человеке, например /note 3 аллергия на орехи")]
+    Note(String),
+    #[command(description = "Добавляет подарок в историю, например /gift 3 2025 книга")]
+    Gift(String),
+    #[command(
+        description = "Выбирает тему форума для напоминаний: отправьте команду в нужной теме"
+    )]
+    Topic,
+    #[command(description = "Включает или отключает закрепление поздравления на день")]
+    Pin,
+    #[command(
+        description = "Выбирает, как отправлять открытки в дни рождения, например /cards photo"
+    )]
+    Cards(String),
+    #[command(description = "Выбирает тему открыток, например /cardtheme night")]
+    CardTheme(String),
+    #[command(
+        description = "Показывает открытку, например /cardpreview 3, где 3 — номер из /list"
+    )]
+    CardPreview(String),
+    #[command(description = "Рисует календарь дней рождения на месяц, например /calendar март")]
+    Calendar(String),
+    #[command(
+        description = "Добавляет опрос ко дню рождения, например /poll 3 Что дарим?; Книгу; Цветы"
+    )]
+    Poll(String),
+    #[command(description = "Отменяет опрос ко дню рождения, например /cancelpoll 2")]
+    CancelPoll(String),
 }
 
 /// Enum defining simple commands for the bot.
 #[derive(BotCommands, Clone)]
-#[command(
-    rename_rule = "lowercase",
-    description = "С помощью этих команд вы можете взаимодействовать и управлять мной.🤖\n\n\
-    Основные команды доступны только для администраторов групп и каналов, а также если вы добавили меня в чат.\n\
-    Проверить свой статус можно с помощью команды /checkcontrol"
-)]
+#[command(rename_rule = "lowercase")]
 pub enum Command {
     /// Displays the hello message for the bot.
     #[command(description = "Отображает приветственное сообщение")]
@@ -83,6 +164,44 @@ pub enum Command {
     /// Sends a sample JSON file with birthdays.
     #[command(description = "Попросить меня отправить вам пример заполненного JSON файла")]
     File,
+    /// Displays the nearest upcoming birthdays.
+    #[command(
+        description = "Отображает ближайшие дни рождения, например /next 10 или /next 10 #backend"
+    )]
+    Next(String),
+    /// Displays the birthdays celebrated today.
+    #[command(description = "Отображает сегодняшние дни рождения")]
+    Today,
+    /// Displays the birthdays celebrated in the given month.
+    #[command(description = "Отображает дни рождения в месяце, например /month март")]
+    Month(String),
+    /// Searches birthdays by name or username.
+    #[command(description = "Ищет дни рождения по имени или username, например /find Иван")]
+    Find(String),
+    /// Adds or updates the birthday of the sender.
+    #[command(
+        description = "Добавляет или обновляет ваш день рождения, например /mybirthday 25-09 или /mybirthday 25-09-1990"
+    )]
+    MyBirthday(String),
+    /// Removes the birthday of the sender.
+    #[command(description = "Удаляет ваш день рождения из списка")]
+    ForgetMe,
+    /// Subscribes the sender to the reminders of a group in private messages.
+    #[command(
+        description = "Подписывает вас на напоминания группы в личных сообщениях: отправьте команду в группе"
+    )]
+    Subscribe,
+    /// Unsubscribes the sender from the reminders of a group in private messages.
+    #[command(description = "Отписывает вас от напоминаний группы в личных сообщениях")]
+    Unsubscribe(String),
+    /// Shows or edits the wishlist of the sender.
+    #[command(
+        description = "Показывает или меняет ваш список желаний, например /mywishlist книга"
+    )]
+    MyWishlist(String),
+    /// Shows the wishlist of a member.
+    #[command(description = "Показывает список желаний участника, например /wishlist Иван")]
+    Wishlist(String),
 }
 
 /// Handles base commands for the bot.
@@ -103,21 +222,41 @@ pub async fn base_commands_handler(
     me: teloxide::types::Me,
     msg: Message,
     cmd: Command,
+    cfg: ConfigParameters,
 ) -> ResponseResult<()> {
     // Determine the user ID of the message sender.
     let user_id = msg.from().unwrap().id;
 
+    // Determine the language of the chat.
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+
     match cmd {
         Command::Start => {
-            bot.send_message(msg.chat.id, GREETINGS_MSG.to_string())
+            bot.send_message(msg.chat.id, lang.messages().greetings)
                 .await?;
         }
-        Command::Help => handle_help_command(&bot, &me, &msg, user_id).await?,
-        Command::CheckControl => handle_check_control_command(&bot, &msg, user_id).await?,
+        Command::Help => handle_help_command(&bot, &me, &msg, user_id, lang).await?,
+        Command::CheckControl => handle_check_control_command(&bot, &msg, user_id, lang).await?,
         Command::File => {
             bot.send_document(msg.chat.id, InputFile::file(SAMPLE_JSON_FILE_PATH))
                 .await?;
         }
+        Command::Next(count) => public::handle_next_command(bot, msg, cfg, count).await?,
+        Command::Today => public::handle_today_command(bot, msg, cfg).await?,
+        Command::Month(month) => public::handle_month_command(bot, msg, cfg, month).await?,
+        Command::Find(query) => public::handle_find_command(bot, msg, cfg, query).await?,
+        Command::MyBirthday(date) => {
+            public::handle_my_birthday_command(bot, msg, cfg, date).await?
+        }
+        Command::ForgetMe => public::handle_forget_me_command(bot, msg, cfg).await?,
+        Command::Subscribe => public::handle_subscribe_command(bot, msg, cfg).await?,
+        Command::Unsubscribe(args) => {
+            public::handle_unsubscribe_command(bot, msg, cfg, args).await?
+        }
+        Command::MyWishlist(args) => {
+            public::handle_my_wishlist_command(bot, msg, cfg, args).await?
+        }
+        Command::Wishlist(name) => public::handle_wishlist_command(bot, msg, cfg, name).await?,
     }
 
     Ok(())
@@ -132,6 +271,7 @@ pub async fn base_commands_handler(
 /// * `bot` - The bot instance
 /// * `msg` - The message triggering the command
 /// * `user_id` - The user id
+/// * `lang` - The language of the chat
 ///
 /// # Returns
 ///
@@ -140,18 +280,29 @@ async fn handle_check_control_command(
     bot: &Bot,
     msg: &Message,
     user_id: UserId,
+    lang: Language,
 ) -> ResponseResult<()> {
-    let place = super::utils::get_place(&msg.chat);
+    let tr = lang.messages();
+    let place = super::utils::get_place(&msg.chat).name(lang);
 
-    let text = if super::utils::is_maintainer(user_id) {
-        CREATOR_MESSAGE.to_string()
+    let mut text = if super::utils::is_maintainer(user_id) {
+        tr.creator.to_string()
     } else {
         match super::utils::is_admin(&bot, msg.chat.id, user_id).await {
-            Ok(is_admin) if is_admin => format!("{}{}!😄", ADMIN_INTERACTION_PREFIX, place),
-            _ => format!("{}{}😞", NO_INTERACTION_PREFIX, place),
+            Ok(is_admin) if is_admin => fill(tr.admin_interaction, &[("place", &place)]),
+            _ => fill(tr.no_interaction, &[("place", &place)]),
         }
     };
 
+    // Greetings and gift collections are pinned only if the bot has the rights to.
+    if msg.chat.is_group() || msg.chat.is_supergroup() {
+        let rights = match super::utils::can_pin_messages(bot, msg.chat.id).await {
+            Ok(true) => tr.pin_rights_granted,
+            _ => tr.pin_rights_missing,
+        };
+        text = format!("{}\n{}", text, rights);
+    }
+
     bot.send_message(msg.chat.id, text).await?;
 
     Ok(())
@@ -167,46 +318,37 @@ async fn handle_check_control_command(
 /// * `me` - Information about the bot itself
 /// * `msg` - The message triggering the command
 /// * `user_id` - The user id
+/// * `lang` - The language of the chat
 ///
 /// # Returns
 ///
 /// A `Result` indicating the success or failure of the command handling.
 async fn handle_help_command(
     bot: &Bot,
-    me: &teloxide::types::Me,
+    me: &Me,
     msg: &Message,
     user_id: UserId,
+    lang: Language,
 ) -> ResponseResult<()> {
-    let place = super::utils::get_place(&msg.chat);
+    let place = super::utils::get_place(&msg.chat).name(lang);
 
     let is_admin = super::utils::is_admin(&bot, msg.chat.id, user_id)
         .await
         .unwrap_or_default();
     let is_maintainer = super::utils::is_maintainer(user_id);
+    let is_group = msg.chat.is_group() || msg.chat.is_supergroup() || msg.chat.is_channel();
 
-    let base_description =
-        if msg.chat.is_group() || msg.chat.is_supergroup() || msg.chat.is_channel() {
-            Command::descriptions().username_from_me(&me).to_string()
-        } else {
-            Command::descriptions().to_string()
-        };
-
-    let admin_description =
-        if msg.chat.is_group() || msg.chat.is_supergroup() || msg.chat.is_channel() {
-            AdminCommands::descriptions()
-                .username_from_me(&me)
-                .to_string()
-        } else {
-            AdminCommands::descriptions().to_string()
-        };
+    let base_description = describe_commands::<Command>(lang, me, is_group);
+    let admin_description = describe_commands::<AdminCommands>(lang, me, is_group);
 
-    let mut text = base_description;
-    if is_maintainer
-        || (is_admin && (msg.chat.is_group() || msg.chat.is_supergroup() || msg.chat.is_channel()))
-        || msg.chat.is_chat()
-    {
+    let mut text = format!(
+        "{}\n\n{}",
+        lang.messages().commands_header,
+        base_description
+    );
+    if is_maintainer || (is_admin && is_group) || msg.chat.is_chat() {
         text = format!(
-            "{}\n{}",
+            "{}\n\n{}",
             text,
             admin_description
                 .lines()
@@ -216,14 +358,7 @@ async fn handle_help_command(
     }
 
     if is_maintainer {
-        let maintainer_description =
-            if msg.chat.is_group() || msg.chat.is_supergroup() || msg.chat.is_channel() {
-                MaintainerCommands::descriptions()
-                    .username_from_me(&me)
-                    .to_string()
-            } else {
-                MaintainerCommands::descriptions().to_string()
-            };
+        let maintainer_description = describe_commands::<MaintainerCommands>(lang, me, is_group);
         text = format!("{}\n{}", text, maintainer_description);
     }
 
@@ -231,3 +366,90 @@ async fn handle_help_command(
 
     Ok(())
 }
+
+/// Function returns the commands of the given enum with descriptions in the given language.
+///
+/// # Arguments
+///
+/// * `lang` - The language of the descriptions
+///
+/// # Returns
+///
+/// A list of bot commands with localized descriptions.
+fn localized_commands<C: BotCommands>(lang: Language) -> Vec<BotCommand> {
+    C::bot_commands()
+        .into_iter()
+        .map(|command| {
+            let description = lang.command_description(&command);
+            BotCommand::new(command.command, description)
+        })
+        .collect()
+}
+
+/// Function returns the text with the commands of the given enum and their descriptions in the
+/// given language.
+///
+/// # Arguments
+///
+/// * `lang` - The language of the descriptions
+/// * `me` - Information about the bot itself
+/// * `is_group` - Whether the commands should be addressed to the bot by its username
+///
+/// # Returns
+///
+/// A `String` with one command per line.
+fn describe_commands<C: BotCommands>(lang: Language, me: &Me, is_group: bool) -> String {
+    let commands = localized_commands::<C>(lang);
+    let descriptions = commands
+        .iter()
+        .map(|command| CommandDescription {
+            prefix: "",
+            command: &command.command,
+            description: &command.description,
+        })
+        .collect::<Vec<_>>();
+    let descriptions = CommandDescriptions::new(&descriptions);
+
+    if is_group {
+        descriptions.username_from_me(me).to_string()
+    } else {
+        descriptions.to_string()
+    }
+}
+
+/// Registers the lists of the bot commands with localized descriptions for every supported
+/// language. Regular members see only the base commands, while administrators and private chats
+/// see the admin commands as well.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance
+///
+/// # Returns
+///
+/// A `Result` indicating the success or failure of the registration.
+pub async fn set_my_commands(bot: &Bot) -> ResponseResult<()> {
+    for lang in Language::ALL {
+        let base_commands = localized_commands::<Command>(lang);
+        let mut admin_commands = base_commands.clone();
+        admin_commands.extend(localized_commands::<AdminCommands>(lang));
+
+        for (scope, commands) in [
+            (BotCommandScope::Default, base_commands.clone()),
+            (
+                BotCommandScope::AllChatAdministrators,
+                admin_commands.clone(),
+            ),
+            (BotCommandScope::AllPrivateChats, admin_commands),
+        ] {
+            let mut request = bot.set_my_commands(commands).scope(scope);
+            // The commands in the default language are shown to users of all other languages.
+            if lang != Language::default() {
+                request = request.language_code(lang.code());
+            }
+            request.await?;
+        }
+    }
+
+    Ok(())
+}
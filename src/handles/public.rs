@@ -0,0 +1,938 @@
+use chrono::{Datelike, Utc};
+use teloxide::payloads::SendMessageSetters;
+use teloxide::prelude::{ChatId, Message, Requester, ResponseResult, UserId};
+use teloxide::Bot;
+
+use crate::i18n::fill;
+use crate::wishlist::{self, WishlistItem};
+use crate::{Birthday, ConfigParameters, EventKind, Language, State};
+
+/// The default number of birthdays displayed by the `next` command.
+const DEFAULT_NEXT_COUNT: usize = 5;
+
+/// The maximum number of birthdays displayed by the `next` command.
+const MAX_NEXT_COUNT: usize = 50;
+
+/// Handles the `next` command for the bot.
+/// This function sends a message to the chat with the nearest upcoming birthdays and the number of
+/// days left until each of them.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The requested number of birthdays, empty for the default, and the optional hashtags
+///   to filter by, e.g. "10 #backend". The birthdays must have all the hashtags.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_next_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Next command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let (tags, count) = match crate::parser::parse_tags(&args) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            bot.send_message(msg.chat.id, err.describe(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    let count = if count.trim().is_empty() {
+        DEFAULT_NEXT_COUNT
+    } else {
+        match count.trim().parse::<usize>() {
+            Ok(count) if count > 0 => count.min(MAX_NEXT_COUNT),
+            _ => {
+                bot.send_message(msg.chat.id, tr.invalid_count).await?;
+                return Ok(());
+            }
+        }
+    };
+
+    if is_hidden(&bot, &msg, &cfg).await {
+        bot.send_message(msg.chat.id, tr.private_list).await?;
+        return Ok(());
+    }
+
+    let text = {
+        let b_map = cfg.b_map.read().await;
+        let today = Utc::now().date_naive();
+
+        let upcoming = b_map
+            .get(&msg.chat.id)
+            .map(|(_, birthdays)| birthdays.upcoming(today, count, &tags))
+            .unwrap_or_default();
+
+        if upcoming.is_empty() {
+            tr.list_empty.to_string()
+        } else {
+            let mut reply_text = format!("{}\n", tr.next_header);
+            for (idx, (birthday, days)) in upcoming.into_iter().enumerate() {
+                let days_text = if days == 0 {
+                    tr.today.to_string()
+                } else {
+                    fill(
+                        tr.days_left,
+                        &[("days", &days), ("days_word", &lang.plural(days, &tr.days))],
+                    )
+                };
+                reply_text += format!(
+                    "{}. {}, {}\n",
+                    idx + 1,
+                    format_birthday(birthday, lang),
+                    days_text
+                )
+                .as_str();
+            }
+            reply_text
+        }
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+/// Handles the `today` command for the bot.
+/// This function sends a message to the chat with the birthdays celebrated today.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_today_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Today command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    if is_hidden(&bot, &msg, &cfg).await {
+        bot.send_message(msg.chat.id, tr.private_list).await?;
+        return Ok(());
+    }
+
+    let text = {
+        let b_map = cfg.b_map.read().await;
+        let today = Utc::now().date_naive();
+        let birthdays = b_map
+            .get(&msg.chat.id)
+            .map(|(_, birthdays)| birthdays.on_date(today))
+            .unwrap_or_default();
+
+        if birthdays.is_empty() {
+            tr.no_birthdays_today.to_string()
+        } else {
+            let (memorial, celebrated): (Vec<_>, Vec<_>) = birthdays
+                .into_iter()
+                .partition(|birthday| birthday.kind == EventKind::Memorial);
+            let mut sections = Vec::new();
+            if !celebrated.is_empty() {
+                let mut section = format!("{}\n", tr.today_header);
+                for birthday in celebrated {
+                    section += format!("🎉 {}\n", format_birthday(birthday, lang)).as_str();
+                }
+                sections.push(section);
+            }
+            if !memorial.is_empty() {
+                let mut section = format!("{}\n", tr.memorial_header);
+                for birthday in memorial {
+                    section += format!("🕯 {}\n", format_birthday(birthday, lang)).as_str();
+                }
+                sections.push(section);
+            }
+            sections.join("\n")
+        }
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+/// Handles the `month` command for the bot.
+/// This function sends a message to the chat with the birthdays celebrated in the requested month,
+/// or in the current month if no month is provided.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `month` - The requested month name or number, empty for the current month.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_month_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    month: String,
+) -> ResponseResult<()> {
+    log::info!("Month command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let month = if month.trim().is_empty() {
+        Utc::now().month()
+    } else if let Some(month) = crate::utils::parse_month(&month) {
+        month
+    } else {
+        bot.send_message(msg.chat.id, tr.invalid_month).await?;
+        return Ok(());
+    };
+
+    if is_hidden(&bot, &msg, &cfg).await {
+        bot.send_message(msg.chat.id, tr.private_list).await?;
+        return Ok(());
+    }
+
+    let text = {
+        let b_map = cfg.b_map.read().await;
+        let birthdays = b_map
+            .get(&msg.chat.id)
+            .map(|(_, birthdays)| birthdays.in_month(month))
+            .unwrap_or_default();
+        let month_name = tr.month_names[month as usize - 1];
+
+        if birthdays.is_empty() {
+            fill(tr.month_empty, &[("month", &month_name)])
+        } else {
+            let mut reply_text = format!("{}:\n", month_name);
+            for birthday in birthdays {
+                reply_text += format!("{}\n", format_birthday(birthday, lang)).as_str();
+            }
+            reply_text
+        }
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+/// Handles the `find` command for the bot.
+/// This function sends a message to the chat with the birthdays whose name or username matches
+/// the query, along with their numbers in the list so they can be edited or removed directly.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `query` - The text to search for.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_find_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    query: String,
+) -> ResponseResult<()> {
+    log::info!("Find command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    if query.trim().trim_start_matches('@').is_empty() {
+        bot.send_message(msg.chat.id, tr.empty_query).await?;
+        return Ok(());
+    }
+
+    if is_hidden(&bot, &msg, &cfg).await {
+        bot.send_message(msg.chat.id, tr.private_list).await?;
+        return Ok(());
+    }
+
+    let text = {
+        let b_map = cfg.b_map.read().await;
+        let found = b_map
+            .get(&msg.chat.id)
+            .map(|(_, birthdays)| birthdays.find(&query))
+            .unwrap_or_default();
+
+        if found.is_empty() {
+            fill(tr.find_nothing, &[("query", &query.trim())])
+        } else {
+            let mut reply_text = format!("{}\n", fill(tr.find_header, &[("query", &query.trim())]));
+            for (idx, birthday) in found {
+                reply_text += format!("{}. {}\n", idx, format_birthday(birthday, lang)).as_str();
+            }
+            reply_text
+        }
+    };
+
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+/// Handles the `mybirthday` command for the bot.
+/// This function adds the birthday of the sender to the list of the chat, or updates it if the
+/// sender has already added it. The name and username are taken from the sender's profile.
+/// The birthdays are accepted unless the notifications are disabled, so the members can register
+/// while the administrators are still filling in the list.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `date` - The date of birth in the format "DD-MM" or "DD-MM-YYYY".
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_my_birthday_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    date: String,
+) -> ResponseResult<()> {
+    log::info!("MyBirthday command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let user = match msg.from() {
+        Some(user) if msg.chat.is_group() || msg.chat.is_supergroup() => user,
+        _ => {
+            bot.send_message(msg.chat.id, tr.group_only).await?;
+            return Ok(());
+        }
+    };
+
+    let mut b_map = cfg.b_map.write().await;
+
+    let order = b_map.get_settings(&msg.chat.id).date_order;
+    let (date, year) = match crate::parser::parse_date(&date, order) {
+        Ok(date) => date,
+        Err(err) => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.invalid_date, &[("error", &err.describe(lang))]),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    if !b_map.get_settings(&msg.chat.id).allow_self_registration {
+        bot.send_message(msg.chat.id, tr.self_registration_forbidden)
+            .await?;
+        return Ok(());
+    }
+
+    let birthday = Birthday {
+        name: user.full_name(),
+        date,
+        username: user
+            .username
+            .as_ref()
+            .map(|username| format!("@{}", username))
+            .unwrap_or_default(),
+        year,
+        user_id: Some(user.id),
+        ..Default::default()
+    };
+
+    match b_map.get_mut(&msg.chat.id) {
+        Some((state, birthdays)) if *state != State::Disabled => {
+            if let Some(in_birthday) = birthdays
+                .position_by_user(user.id)
+                .and_then(|idx| birthdays.get_mut(idx))
+            {
+                // Keep the tags, notes and gifts added by the administrators.
+                *in_birthday = Birthday {
+                    tags: std::mem::take(&mut in_birthday.tags),
+                    notes: std::mem::take(&mut in_birthday.notes),
+                    gifts: std::mem::take(&mut in_birthday.gifts),
+                    ..birthday
+                };
+                log::info!(
+                    "Birthday of user {} updated for chat id {}",
+                    user.id,
+                    msg.chat.id
+                );
+                bot.send_message(msg.chat.id, tr.my_birthday_updated)
+                    .await?;
+            } else if let Err(err) = b_map.update_birthdays(&msg.chat.id, birthday) {
+                log::error!("Birthday not added for chat id {}: {:?}", msg.chat.id, err);
+                bot.send_message(msg.chat.id, tr.busy).await?;
+            } else {
+                log::info!(
+                    "Birthday of user {} added for chat id {}",
+                    user.id,
+                    msg.chat.id
+                );
+                bot.send_message(msg.chat.id, tr.my_birthday_added).await?;
+            }
+        }
+        _ => {
+            bot.send_message(msg.chat.id, tr.not_active).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `forgetme` command for the bot.
+/// This function removes the birthday linked to the sender from the list of the chat.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_forget_me_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("ForgetMe command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let user = match msg.from() {
+        Some(user) if msg.chat.is_group() || msg.chat.is_supergroup() => user,
+        _ => {
+            bot.send_message(msg.chat.id, tr.group_only).await?;
+            return Ok(());
+        }
+    };
+
+    let mut b_map = cfg.b_map.write().await;
+
+    let index = b_map
+        .get(&msg.chat.id)
+        .and_then(|(_, birthdays)| birthdays.position_by_user(user.id));
+
+    match index.and_then(|idx| b_map.remove_birthday(&msg.chat.id, idx)) {
+        Some(_) => {
+            log::info!(
+                "Birthday of user {} removed for chat id {}",
+                user.id,
+                msg.chat.id
+            );
+            bot.send_message(msg.chat.id, tr.my_birthday_removed)
+                .await?;
+        }
+        None => {
+            bot.send_message(msg.chat.id, tr.not_registered).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `subscribe` command for the bot.
+/// Sent in a group with active notifications, this function subscribes the sender to the reminders
+/// of the group so they are delivered to the private chat, and confirms the subscription there. If
+/// the bot can't write to the sender, the subscription is cancelled and the sender is asked to start
+/// a private chat with the bot first. Sent in a private chat, the command explains how to subscribe.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_subscribe_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+) -> ResponseResult<()> {
+    log::info!("Subscribe command received from chat id {}", msg.chat.id);
+
+    let chat_id = msg.chat.id;
+    let tr = cfg
+        .b_map
+        .read()
+        .await
+        .get_settings(&chat_id)
+        .language
+        .messages();
+
+    let user_id = match msg.from() {
+        Some(user) if !msg.chat.is_private() => user.id,
+        _ => {
+            bot.send_message(chat_id, tr.subscribe_usage).await?;
+            return Ok(());
+        }
+    };
+    let private_chat_id = ChatId(user_id.0 as i64);
+    let title = crate::utils::get_chat_title(&bot, chat_id).await;
+
+    let private_lang = {
+        let mut b_map = cfg.b_map.write().await;
+        if !matches!(b_map.get(&chat_id), Some((State::Active, _))) {
+            drop(b_map);
+            bot.send_message(chat_id, tr.subscribe_inactive).await?;
+            return Ok(());
+        }
+        let mut settings = b_map.get_settings(&chat_id);
+        if settings.subscribers.contains(&user_id) {
+            drop(b_map);
+            bot.send_message(chat_id, fill(tr.already_subscribed, &[("chat", &title)]))
+                .await?;
+            return Ok(());
+        }
+        settings.subscribers.push(user_id);
+        if b_map.update_settings(&chat_id, settings).is_err() {
+            drop(b_map);
+            bot.send_message(chat_id, tr.busy).await?;
+            return Ok(());
+        }
+        b_map.get_settings(&private_chat_id).language
+    };
+
+    // The reminders are delivered only if the member has started a private chat with the bot.
+    let confirmation = bot
+        .send_message(
+            private_chat_id,
+            fill(private_lang.messages().subscribed, &[("chat", &title)]),
+        )
+        .await;
+    match confirmation {
+        Ok(_) => {
+            log::info!("User {} subscribed to chat id {}", user_id, chat_id);
+            bot.send_message(chat_id, tr.subscribed_in_group).await?;
+        }
+        Err(e) => {
+            log::warn!("User {} can't receive private messages: {}", user_id, e);
+            {
+                let mut b_map = cfg.b_map.write().await;
+                let mut settings = b_map.get_settings(&chat_id);
+                settings
+                    .subscribers
+                    .retain(|subscriber| *subscriber != user_id);
+                if let Err(err) = b_map.update_settings(&chat_id, settings) {
+                    log::error!("Settings not updated for chat id {}: {:?}", chat_id, err);
+                }
+            }
+            bot.send_message(chat_id, tr.subscribe_start_private)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `unsubscribe` command for the bot.
+/// Without arguments, this function sends a message to the private chat with the numbered list of
+/// groups the sender is subscribed to. With a number, it unsubscribes the sender from the reminders
+/// of the group.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The number of the group in the list.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_unsubscribe_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("Unsubscribe command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let user_id = match msg.from() {
+        Some(user) if msg.chat.is_private() => user.id,
+        _ => {
+            bot.send_message(msg.chat.id, tr.private_only).await?;
+            return Ok(());
+        }
+    };
+
+    let mut chats = subscriptions(&cfg, user_id).await;
+    chats.sort();
+
+    if args.trim().is_empty() {
+        let text = if chats.is_empty() {
+            tr.no_subscriptions.to_string()
+        } else {
+            format!(
+                "{}\n{}\n{}",
+                tr.unsubscribe_list_header,
+                list_chats(&bot, &chats, &[]).await,
+                tr.unsubscribe_usage
+            )
+        };
+        bot.send_message(msg.chat.id, text).await?;
+        return Ok(());
+    }
+
+    let chat_id = match crate::utils::parse_birthday_index(args.trim()) {
+        Some(index) if (1..=chats.len()).contains(&index) => chats[index - 1],
+        _ => {
+            bot.send_message(msg.chat.id, tr.subscription_not_found)
+                .await?;
+            return Ok(());
+        }
+    };
+    let title = crate::utils::get_chat_title(&bot, chat_id).await;
+
+    let mut b_map = cfg.b_map.write().await;
+    let mut settings = b_map.get_settings(&chat_id);
+    settings
+        .subscribers
+        .retain(|subscriber| *subscriber != user_id);
+
+    match b_map.update_settings(&chat_id, settings) {
+        Ok(_) => {
+            log::info!("User {} unsubscribed from chat id {}", user_id, chat_id);
+            bot.send_message(msg.chat.id, fill(tr.unsubscribed, &[("chat", &title)]))
+                .await?;
+        }
+        Err(_) => {
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `mywishlist` command for the bot.
+/// Without arguments, this function sends a message to the private chat with the wishlist of the
+/// sender. The `remove` argument with a number removes the item, the `clear` argument alone removes
+/// all items, and any other text, e.g. "clear skin serum", is added as a new item. The items claimed
+/// by other members are never shown to the sender and are kept by these operations.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `args` - The arguments of the command.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_my_wishlist_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    args: String,
+) -> ResponseResult<()> {
+    log::info!("MyWishlist command received from chat id {}", msg.chat.id);
+
+    let mut b_map = cfg.b_map.write().await;
+    let lang = b_map.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    let user_id = match msg.from() {
+        Some(user) if msg.chat.is_private() => user.id,
+        _ => {
+            bot.send_message(msg.chat.id, tr.private_only).await?;
+            return Ok(());
+        }
+    };
+
+    let mut items = b_map.wishlist(&user_id).to_vec();
+    let visible = wishlist::unclaimed(&items)
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let args = args.trim();
+    let (action, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+    let index = crate::utils::parse_birthday_index(rest.trim());
+
+    let text = match (action.to_lowercase().as_str(), index) {
+        ("", _) => {
+            let list = if visible.is_empty() {
+                tr.my_wishlist_empty.to_string()
+            } else {
+                visible
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, index)| format!("{}. {}\n", idx + 1, items[*index].text))
+                    .collect::<String>()
+            };
+            bot.send_message(
+                msg.chat.id,
+                format!(
+                    "{}\n{}\n{}",
+                    tr.my_wishlist_header,
+                    list,
+                    fill(
+                        tr.my_wishlist_usage,
+                        &[("max", &wishlist::MAX_WISHLIST_ITEMS)]
+                    )
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+        ("remove", Some(index)) => {
+            if !(1..=visible.len()).contains(&index) {
+                bot.send_message(msg.chat.id, tr.wishlist_item_index_not_found)
+                    .await?;
+                return Ok(());
+            }
+            items.remove(visible[index - 1]);
+            fill(tr.wishlist_item_removed, &[("index", &index)])
+        }
+        ("clear", _) if rest.trim().is_empty() => {
+            items.retain(|item| item.claimed_by.is_some());
+            tr.wishlist_cleared.to_string()
+        }
+        _ => {
+            if items.len() >= wishlist::MAX_WISHLIST_ITEMS {
+                bot.send_message(
+                    msg.chat.id,
+                    fill(
+                        tr.wishlist_too_many,
+                        &[("max", &wishlist::MAX_WISHLIST_ITEMS)],
+                    ),
+                )
+                .await?;
+                return Ok(());
+            }
+            if args.chars().count() > wishlist::ITEM_MAX_LEN {
+                bot.send_message(
+                    msg.chat.id,
+                    fill(
+                        tr.wishlist_item_too_long,
+                        &[("max", &wishlist::ITEM_MAX_LEN)],
+                    ),
+                )
+                .await?;
+                return Ok(());
+            }
+            items.push(WishlistItem {
+                id: 0,
+                text: args.to_string(),
+                claimed_by: None,
+            });
+            tr.wishlist_item_added.to_string()
+        }
+    };
+
+    match b_map.update_wishlist(&user_id, items) {
+        Ok(_) => {
+            log::info!("Wishlist of user {} updated", user_id);
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Err(err) => {
+            log::error!("Wishlist of user {} not updated: {:?}", user_id, err);
+            bot.send_message(msg.chat.id, tr.busy).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the `wishlist` command for the bot.
+/// This function sends a message to the chat with the unclaimed items of the wishlist of the
+/// member with the given name and the buttons to claim them.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+/// * `name` - The name or the username of the member.
+///
+/// # Returns
+///
+/// A `ResponseResult` indicating the success or failure of the command.
+pub async fn handle_wishlist_command(
+    bot: Bot,
+    msg: Message,
+    cfg: ConfigParameters,
+    name: String,
+) -> ResponseResult<()> {
+    log::info!("Wishlist command received from chat id {}", msg.chat.id);
+
+    let lang = cfg.b_map.read().await.get_settings(&msg.chat.id).language;
+    let tr = lang.messages();
+
+    if name.trim().is_empty() {
+        bot.send_message(msg.chat.id, tr.wishlist_usage).await?;
+        return Ok(());
+    }
+
+    if is_hidden(&bot, &msg, &cfg).await {
+        bot.send_message(msg.chat.id, tr.private_list).await?;
+        return Ok(());
+    }
+
+    let found = {
+        let b_map = cfg.b_map.read().await;
+        let linked = b_map
+            .get(&msg.chat.id)
+            .map(|(_, birthdays)| {
+                birthdays
+                    .find(name.trim())
+                    .into_iter()
+                    .filter_map(|(_, birthday)| {
+                        birthday
+                            .user_id
+                            .map(|user_id| (user_id, birthday.name.clone()))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        match linked.as_slice() {
+            [(user_id, name)] => Some((*user_id, name.clone(), b_map.wishlist(user_id).to_vec())),
+            _ => None,
+        }
+    };
+
+    match found {
+        Some((user_id, name, items)) => {
+            let text = wishlist::text(&name, &items, lang);
+            bot.send_message(msg.chat.id, text)
+                .reply_markup(wishlist::keyboard(user_id, &items, lang))
+                .await?;
+        }
+        None => {
+            bot.send_message(
+                msg.chat.id,
+                fill(tr.wishlist_not_found, &[("query", &name.trim())]),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Function returns the chats the user is subscribed to.
+///
+/// # Arguments
+///
+/// * `cfg` - Configuration parameters for the bot.
+/// * `user_id` - The user id.
+///
+/// # Returns
+///
+/// A list of chat IDs.
+async fn subscriptions(cfg: &ConfigParameters, user_id: UserId) -> Vec<ChatId> {
+    let b_map = cfg.b_map.read().await;
+    b_map
+        .iter()
+        .map(|(chat_id, _)| *chat_id)
+        .filter(|chat_id| b_map.get_settings(chat_id).subscribers.contains(&user_id))
+        .collect()
+}
+
+/// Function formats the numbered list of chats with their titles.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `chats` - The chats to list.
+/// * `marked` - Whether each chat is marked as subscribed.
+///
+/// # Returns
+///
+/// A `String` with one chat per line.
+async fn list_chats(bot: &Bot, chats: &[ChatId], marked: &[bool]) -> String {
+    let mut text = String::new();
+    for (idx, chat_id) in chats.iter().enumerate() {
+        let title = crate::utils::get_chat_title(bot, *chat_id).await;
+        let mark = if marked.get(idx).copied().unwrap_or_default() {
+            " ✅"
+        } else {
+            ""
+        };
+        text += format!("{}. {}{}\n", idx + 1, title, mark).as_str();
+    }
+    text
+}
+
+/// Function checks that the list of birthdays is hidden from the sender of the message.
+/// The list is hidden when the chat is private and the sender is neither an administrator of the
+/// chat nor the maintainer of the bot.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `msg` - The message triggering the command.
+/// * `cfg` - Configuration parameters for the bot.
+///
+/// # Returns
+///
+/// A `bool` indicating the list is hidden from the sender or not.
+async fn is_hidden(bot: &Bot, msg: &Message, cfg: &ConfigParameters) -> bool {
+    if !cfg.b_map.read().await.get_settings(&msg.chat.id).is_private || msg.chat.is_private() {
+        return false;
+    }
+
+    match msg.from() {
+        Some(user) if user.id == cfg.bot_maintainer => false,
+        Some(user) => !crate::utils::is_admin(bot, msg.chat.id, user.id)
+            .await
+            .unwrap_or_default(),
+        None => true,
+    }
+}
+
+/// Function formats the birthday as a single line of text.
+///
+/// # Arguments
+///
+/// * `birthday` - The birthday to format.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// A `String` with the name, username, kind and date of the birthday.
+fn format_birthday(birthday: &Birthday, lang: Language) -> String {
+    if birthday.username.is_empty() {
+        format!(
+            "{}{} — {}",
+            birthday.name,
+            birthday.kind_label(lang),
+            birthday.date
+        )
+    } else {
+        format!(
+            "{} ({}){} — {}",
+            birthday.name,
+            birthday.username,
+            birthday.kind_label(lang),
+            birthday.date
+        )
+    }
+}
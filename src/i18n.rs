@@ -0,0 +1,1444 @@
+use serde::{Deserialize, Serialize};
+use teloxide::types::BotCommand;
+
+/// Represents the language of the messages sent by the bot.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    Russian,
+    English,
+}
+
+impl Language {
+    /// All languages supported by the bot.
+    pub const ALL: [Language; 2] = [Language::Russian, Language::English];
+
+    /// Returns the two-letter ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Russian => "ru",
+            Language::English => "en",
+        }
+    }
+
+    /// Returns the name of the language in the language itself.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Russian => "Русский",
+            Language::English => "English",
+        }
+    }
+
+    /// Returns the language with the given two-letter ISO 639-1 code or name.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The code or the name of the language, case-insensitive.
+    ///
+    /// # Returns
+    ///
+    /// Some language if it is supported, otherwise None.
+    pub fn parse(input: &str) -> Option<Language> {
+        let input = input.trim().to_lowercase();
+        Language::ALL
+            .into_iter()
+            .find(|lang| lang.code() == input || lang.name().to_lowercase() == input)
+    }
+
+    /// Returns the message catalogue for the language.
+    pub fn messages(&self) -> &'static Messages {
+        match self {
+            Language::Russian => &RU,
+            Language::English => &EN,
+        }
+    }
+
+    /// Returns the plural form of a word for the given number.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number the word refers to.
+    /// * `forms` - The forms of the word for one, few and many items, e.g. "день", "дня" and "дней".
+    ///
+    /// # Returns
+    ///
+    /// The form of the word matching the number.
+    pub fn plural(&self, n: i64, forms: &[&'static str; 3]) -> &'static str {
+        let n = n.abs();
+        match self {
+            Language::Russian => {
+                if n % 10 == 1 && n % 100 != 11 {
+                    forms[0]
+                } else if (2..=4).contains(&(n % 10)) && !(12..=14).contains(&(n % 100)) {
+                    forms[1]
+                } else {
+                    forms[2]
+                }
+            }
+            Language::English => {
+                if n == 1 {
+                    forms[0]
+                } else {
+                    forms[2]
+                }
+            }
+        }
+    }
+
+    /// Returns the description of the bot command in the language.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The bot command with the description declared on the command enum.
+    ///
+    /// # Returns
+    ///
+    /// The localized description, or the declared one if there is no translation.
+    pub fn command_description(&self, command: &BotCommand) -> String {
+        let name = command.command.trim_start_matches('/');
+        self.messages()
+            .command_descriptions
+            .iter()
+            .find(|(cmd, _)| *cmd == name)
+            .map(|(_, description)| description.to_string())
+            .unwrap_or_else(|| command.description.clone())
+    }
+}
+
+/// Replaces the `{key}` placeholders in the template with the given values.
+/// The template is scanned once from left to right, so the placeholders inside the inserted
+/// values, e.g. in the names of the people, are kept as they are. Unknown placeholders are kept
+/// as well.
+///
+/// # Arguments
+///
+/// * `template` - The template with placeholders.
+/// * `args` - The pairs of placeholder names and values.
+///
+/// # Returns
+///
+/// A `String` with the placeholders replaced.
+pub fn fill(template: &str, args: &[(&str, &(dyn std::fmt::Display + Sync))]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            args.iter()
+                .find(|(key, _)| *key == &after[..end])
+                .map(|(_, value)| (end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                text.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Represents the catalogue of the messages sent by the bot in one language.
+/// Placeholders in curly braces are replaced with `fill`.
+pub struct Messages {
+    /// The descriptions of the bot commands overriding the ones declared on the command enums.
+    pub command_descriptions: &'static [(&'static str, &'static str)],
+    /// The description shown before the list of commands.
+    pub commands_header: &'static str,
+    /// The greetings message for the bot.
+    pub greetings: &'static str,
+    /// The messages to send when checking the control requested.
+    pub creator: &'static str,
+    pub admin_interaction: &'static str,
+    pub no_interaction: &'static str,
+    pub pin_rights_granted: &'static str,
+    pub pin_rights_missing: &'static str,
+    /// The names of places where bot is used.
+    pub place_group: &'static str,
+    pub place_channel: &'static str,
+    pub place_chat: &'static str,
+    /// The message to send when the bot is busy (limit of birthdays reached).
+    pub busy: &'static str,
+    /// The list of birthdays.
+    pub list_empty: &'static str,
+    pub list_header: &'static str,
+    pub list_usage: &'static str,
+    pub export_usage: &'static str,
+    pub export_failed: &'static str,
+    /// The default birthday greeting.
+    pub reminder: &'static str,
+    /// The default reminder sent before the birthday.
+    pub advance_reminder: &'static str,
+    /// The default greetings on the day of other events and the default reminder sent before them.
+    pub work_anniversary_reminder: &'static str,
+    pub wedding_reminder: &'static str,
+    pub name_day_reminder: &'static str,
+    pub memorial_reminder: &'static str,
+    pub event_advance_reminder: &'static str,
+    /// The digest of the reminders of one day.
+    pub digest_advance_header: &'static str,
+    pub digest_footer: &'static str,
+    /// The scheduled digests of the week and the month.
+    pub weekly_digest_header: &'static str,
+    pub monthly_digest_header: &'static str,
+    /// The names of the months in the nominative case.
+    pub month_names: [&'static str; 12],
+    /// The forms of the word "day" for one, few and many days.
+    pub days: [&'static str; 3],
+    /// The forms of the word "year" for one, few and many years.
+    pub years: [&'static str; 3],
+    /// The names of the kinds of events.
+    pub event_birthday: &'static str,
+    pub event_work_anniversary: &'static str,
+    pub event_wedding: &'static str,
+    pub event_name_day: &'static str,
+    pub event_memorial: &'static str,
+
+    /// The messages of the admin commands.
+    pub json: &'static str,
+    pub add: &'static str,
+    pub add_reply_invalid_date: &'static str,
+    pub remove: &'static str,
+    pub cancel: &'static str,
+    pub cancel_empty_list: &'static str,
+    pub cancel_already_disabled: &'static str,
+    pub active: &'static str,
+    pub active_already_active: &'static str,
+    pub active_waiting_json: &'static str,
+    pub active_empty_list: &'static str,
+    pub active_waiting_birthday: &'static str,
+    pub disable: &'static str,
+    pub disable_already_disabled: &'static str,
+    pub disable_waiting: &'static str,
+    pub disable_empty_list: &'static str,
+    pub privacy_enabled: &'static str,
+    pub privacy_disabled: &'static str,
+    pub self_registration_enabled: &'static str,
+    pub self_registration_disabled: &'static str,
+    pub date_order_day_month: &'static str,
+    pub date_order_month_day: &'static str,
+    pub language_changed: &'static str,
+    pub language_usage: &'static str,
+    pub advance_enabled: &'static str,
+    pub advance_disabled: &'static str,
+    pub advance_usage: &'static str,
+    pub greetings_header: &'static str,
+    pub advance_greetings_header: &'static str,
+    pub event_greetings_header: &'static str,
+    pub event_advance_greetings_header: &'static str,
+    pub greetings_default: &'static str,
+    pub greetings_usage: &'static str,
+    pub greeting_added: &'static str,
+    pub greeting_removed: &'static str,
+    pub greeting_not_found: &'static str,
+    pub greetings_reset: &'static str,
+    pub greetings_too_many: &'static str,
+    pub invalid_template: &'static str,
+    pub combine_enabled: &'static str,
+    pub combine_disabled: &'static str,
+    pub digest_usage: &'static str,
+    pub digest_on: &'static str,
+    pub digest_off: &'static str,
+    pub weekly_digest_enabled: &'static str,
+    pub weekly_digest_disabled: &'static str,
+    pub monthly_digest_enabled: &'static str,
+    pub monthly_digest_disabled: &'static str,
+    pub surprise_enabled: &'static str,
+    pub surprise_disabled: &'static str,
+    pub name_days_enabled: &'static str,
+    pub name_days_disabled: &'static str,
+    /// The announcement of the name days found in the calendar.
+    pub name_day_calendar_reminder: &'static str,
+    pub tags_usage: &'static str,
+    pub tags_updated: &'static str,
+    pub tags_cleared: &'static str,
+    pub tag_rule_usage: &'static str,
+    pub tag_rules_header: &'static str,
+    pub tag_rule_set: &'static str,
+    /// The descriptions of the rules of delivering the reminders by tags.
+    pub delivery_all: &'static str,
+    pub delivery_advance: &'static str,
+    pub delivery_private: &'static str,
+    pub delivery_off: &'static str,
+    pub notes_usage: &'static str,
+    pub notes_header: &'static str,
+    pub notes_empty: &'static str,
+    pub gifts_header: &'static str,
+    pub gifts_empty: &'static str,
+    pub notes_sent_privately: &'static str,
+    pub notes_private_failed: &'static str,
+    pub note_usage: &'static str,
+    pub note_too_long: &'static str,
+    pub notes_too_many: &'static str,
+    pub note_added: &'static str,
+    pub gift_usage: &'static str,
+    pub gifts_too_many: &'static str,
+    pub private_not_hidden: &'static str,
+    pub gift_added: &'static str,
+    pub topic_not_forum: &'static str,
+    pub topic_set: &'static str,
+    pub topic_reset: &'static str,
+    pub pin_enabled: &'static str,
+    pub pin_disabled: &'static str,
+    /// The notice to the administrators when the greeting could not be pinned.
+    pub pin_failed: &'static str,
+    /// The notice to the administrators when the chosen forum topic is deleted or closed.
+    pub topic_lost: &'static str,
+    /// The descriptions of the ways the greeting cards are sent.
+    pub card_mode_off: &'static str,
+    pub card_mode_photo: &'static str,
+    pub card_mode_both: &'static str,
+    /// The descriptions of the background themes of the greeting cards.
+    pub card_theme_confetti: &'static str,
+    pub card_theme_night: &'static str,
+    pub card_theme_pastel: &'static str,
+    pub card_theme_classic: &'static str,
+    pub cards_usage: &'static str,
+    pub cards_set: &'static str,
+    pub card_theme_usage: &'static str,
+    pub card_theme_set: &'static str,
+    pub card_preview_usage: &'static str,
+    pub card_preview_caption: &'static str,
+    pub card_preview_failed: &'static str,
+    /// The texts drawn on the greeting cards.
+    pub card_title: &'static str,
+    pub card_age: &'static str,
+    pub calendar_caption: &'static str,
+    pub calendar_failed: &'static str,
+    pub poll_usage: &'static str,
+    pub poll_birthday_not_found: &'static str,
+    pub poll_too_soon: &'static str,
+    pub poll_surprise: &'static str,
+    pub poll_exists: &'static str,
+    pub polls_too_many: &'static str,
+    pub poll_too_few_options: &'static str,
+    pub poll_too_many_options: &'static str,
+    pub poll_question_too_long: &'static str,
+    pub poll_option_too_long: &'static str,
+    pub poll_added: &'static str,
+    pub poll_needs_advance: &'static str,
+    pub polls_header: &'static str,
+    pub poll_posted: &'static str,
+    pub poll_waiting: &'static str,
+    pub poll_cancelled: &'static str,
+    pub poll_not_found: &'static str,
+    /// The summary of the celebration poll sent the day before the birthday.
+    pub poll_summary: &'static str,
+    pub votes: [&'static str; 3],
+    pub poll_winner: &'static str,
+    pub poll_tie: &'static str,
+    pub poll_no_votes: &'static str,
+    pub collection_usage: &'static str,
+    pub collection_birthday_not_found: &'static str,
+    pub collection_ambiguous: &'static str,
+    pub collection_exists: &'static str,
+
+    /// The messages of the gift collection.
+    pub collection_text: &'static str,
+    pub collection_closed_text: &'static str,
+    pub collection_no_contributors: &'static str,
+    pub collection_paid_button: &'static str,
+    pub collection_close_button: &'static str,
+    pub collection_paid: &'static str,
+    pub collection_unpaid: &'static str,
+    pub collection_closed: &'static str,
+    pub collection_close_forbidden: &'static str,
+    pub collection_not_found: &'static str,
+    pub event_usage: &'static str,
+    pub invalid_event: &'static str,
+    pub events_too_many: &'static str,
+    pub event_added: &'static str,
+    pub events_empty: &'static str,
+    pub events_header: &'static str,
+    pub event_next: &'static str,
+    pub event_cancelled: &'static str,
+    pub event_not_found: &'static str,
+    /// The reminder about the scheduled event and the descriptions of the recurrence rules.
+    pub scheduled_event_reminder: &'static str,
+    pub recurrence_once: &'static str,
+    pub recurrence_weekly: &'static str,
+    pub recurrence_monthly_day: &'static str,
+    pub recurrence_monthly_weekday: &'static str,
+    pub recurrence_last_weekday: &'static str,
+    /// The names of the weekdays starting from Monday and the ordinal numbers of the weeks.
+    pub weekdays: [&'static str; 7],
+    /// The short names of the weekdays starting from Monday, used in the calendar.
+    pub weekdays_short: [&'static str; 7],
+    pub week_ordinals: [&'static str; 4],
+
+    /// The messages of the adding and removing modes.
+    pub invalid_format: &'static str,
+    pub invalid_input: &'static str,
+    pub birthday_added: &'static str,
+    pub too_many_lines: &'static str,
+    pub line_added: &'static str,
+    pub line_failed: &'static str,
+    pub lines_summary: &'static str,
+    pub birthdays_loaded: &'static str,
+    pub invalid_file: &'static str,
+    pub birthday_removed: &'static str,
+    pub birthday_not_found: &'static str,
+
+    /// The messages of the commands available to all members.
+    pub private_list: &'static str,
+    pub invalid_count: &'static str,
+    pub invalid_month: &'static str,
+    pub empty_query: &'static str,
+    pub invalid_date: &'static str,
+    pub group_only: &'static str,
+    pub not_active: &'static str,
+    pub self_registration_forbidden: &'static str,
+    pub not_registered: &'static str,
+    pub next_header: &'static str,
+    pub today: &'static str,
+    pub days_left: &'static str,
+    pub today_header: &'static str,
+    pub memorial_header: &'static str,
+    pub no_birthdays_today: &'static str,
+    pub month_empty: &'static str,
+    pub find_nothing: &'static str,
+    pub find_header: &'static str,
+    pub my_birthday_added: &'static str,
+    pub my_birthday_updated: &'static str,
+    pub my_birthday_removed: &'static str,
+    pub private_only: &'static str,
+    pub subscribe_usage: &'static str,
+    pub subscribe_inactive: &'static str,
+    pub subscribe_start_private: &'static str,
+    pub subscribed_in_group: &'static str,
+    pub unsubscribe_list_header: &'static str,
+    pub unsubscribe_usage: &'static str,
+    pub no_subscriptions: &'static str,
+    pub subscription_not_found: &'static str,
+    pub subscribed: &'static str,
+    pub already_subscribed: &'static str,
+    pub unsubscribed: &'static str,
+    pub subscription_header: &'static str,
+    pub my_wishlist_header: &'static str,
+    pub my_wishlist_empty: &'static str,
+    pub my_wishlist_usage: &'static str,
+    pub wishlist_item_added: &'static str,
+    pub wishlist_item_removed: &'static str,
+    pub wishlist_item_index_not_found: &'static str,
+    pub wishlist_cleared: &'static str,
+    pub wishlist_too_many: &'static str,
+    pub wishlist_item_too_long: &'static str,
+    pub wishlist_usage: &'static str,
+    pub wishlist_not_found: &'static str,
+    pub wishlist_header: &'static str,
+    pub wishlist_empty: &'static str,
+    pub wishlist_hint: &'static str,
+    pub wishlist_claim_button: &'static str,
+    pub wishlist_show_button: &'static str,
+    pub wishlist_claimed: &'static str,
+    pub wishlist_unclaimed: &'static str,
+    pub wishlist_already_claimed: &'static str,
+    pub wishlist_own_claim: &'static str,
+    pub wishlist_item_not_found: &'static str,
+
+    /// The explanations of the parsing errors.
+    pub parse_missing_separator: &'static str,
+    pub parse_empty_name: &'static str,
+    pub parse_name_too_long: &'static str,
+    pub parse_invalid_name_char: &'static str,
+    pub parse_missing_date: &'static str,
+    pub parse_unknown_date: &'static str,
+    pub parse_unknown_month: &'static str,
+    pub parse_invalid_month: &'static str,
+    pub parse_invalid_day: &'static str,
+    pub parse_short_year: &'static str,
+    pub parse_invalid_year: &'static str,
+    pub parse_invalid_username: &'static str,
+    pub parse_unknown_kind: &'static str,
+    pub parse_invalid_tag: &'static str,
+
+    /// The explanations of the errors in scheduled events.
+    pub schedule_missing_separator: &'static str,
+    pub schedule_empty_title: &'static str,
+    pub schedule_title_too_long: &'static str,
+    pub schedule_past_date: &'static str,
+    pub schedule_unknown_rule: &'static str,
+
+    /// The explanations of the greeting template errors.
+    pub template_empty: &'static str,
+    pub template_too_long: &'static str,
+    pub template_unknown_placeholder: &'static str,
+    pub template_unclosed_brace: &'static str,
+    pub template_no_person: &'static str,
+}
+
+/// The Russian messages.
+pub const RU: Messages = Messages {
+    command_descriptions: &[],
+    commands_header: "С помощью этих команд вы можете взаимодействовать и управлять мной.🤖\n\n\
+        Основные команды доступны только для администраторов групп и каналов, а также если вы добавили меня в чат.\n\
+        Проверить свой статус можно с помощью команды /checkcontrol",
+    greetings: "Привет! Этот бот создан для тех, кто постоянно забывает про дни рождения😁\n
+С помощью него вы никогда не забудете поздравить своих друзей, коллег по работе или родственников.\n
+Для более подробной информации по настройке используйте команду /help.",
+    creator: "Вы мой создатель!🙏",
+    admin_interaction: "Вы можете взаимодействовать со мной {place}!😄",
+    no_interaction: "К сожалению, вы не можете взаимодействовать со мной {place}😞",
+    pin_rights_granted: "📌 Я могу закреплять сообщения в этом чате",
+    pin_rights_missing: "⚠️ У меня нет права закреплять сообщения, поэтому поздравления и сборы на подарки \
+        не будут закреплены. Сделайте меня администратором с правом закрепления сообщений",
+    place_group: "в группе",
+    place_channel: "в канале",
+    place_chat: "в чате",
+    busy: "К сожалению, в данный момент я не могу принимать новые запросы из-за высокой нагрузки 😞 \
+        Попробуйте повторить запрос позже",
+    list_empty: "Список дней рождений пуст",
+    list_header: "Список дней рождений:",
+    list_usage: "Укажите вид событий, например /list work, и/или тег, например /list #backend, или отправьте \
+        /list без аргументов, чтобы увидеть все события. Виды событий: {kinds}",
+    export_usage: "Укажите теги, например /export #backend, или отправьте /export без аргументов, чтобы \
+        выгрузить все события. Заметки и подарки выгружаются только по команде /export notes",
+    export_failed: "Не удалось подготовить файл 😔 Попробуйте позже",
+    reminder: "Поздравьте сегодня замечательного человека с днем рождения {mention}!🎉",
+    advance_reminder: "Через {days_left} день рождения у {mention}! Не забудьте подготовить поздравление 🎁",
+    work_anniversary_reminder: "Сегодня годовщина работы {mention} в команде! Спасибо, что вы с нами 💼",
+    wedding_reminder: "Сегодня годовщина свадьбы у {mention}! Поздравляем 💍",
+    name_day_reminder: "Сегодня именины у {mention}! Не забудьте поздравить 😇",
+    memorial_reminder: "Сегодня памятная дата: {name} 🕯",
+    event_advance_reminder: "Через {days_left} — {event}: {mention} 🔔",
+    digest_advance_header: "Через {days_left}:",
+    digest_footer: "Не забудьте поздравить! 🎉",
+    weekly_digest_header: "Дни рождения на этой неделе:",
+    monthly_digest_header: "Дни рождения в этом месяце ({month}):",
+    month_names: [
+        "Январь",
+        "Февраль",
+        "Март",
+        "Апрель",
+        "Май",
+        "Июнь",
+        "Июль",
+        "Август",
+        "Сентябрь",
+        "Октябрь",
+        "Ноябрь",
+        "Декабрь",
+    ],
+    days: ["день", "дня", "дней"],
+    years: ["год", "года", "лет"],
+    event_birthday: "день рождения",
+    event_work_anniversary: "годовщина работы",
+    event_wedding: "годовщина свадьбы",
+    event_name_day: "именины",
+    event_memorial: "памятная дата",
+
+    json: "Отправьте мне заполненный JSON файл с указанием дней рождений. Я отправил вам пример того, \
+        как должен выглядеть файл",
+    add: "Отправьте мне день рождения в формате 'Имя Фамилия, дата, @username' или 'Имя Фамилия, дата'. \
+        Дату можно указать как '5 марта', 'March 5', '05.03', '5/3' или '05-03', а также добавить год, \
+        например 'Иван Иванов, 5 марта 1990, @ivan' или 'Анна-Мария Петрова, 05.03'.\n \
+        Можно отправить сразу несколько дней рождений, по одному на строке.\n \
+        Чтобы я упоминал человека в поздравлении даже без username, отправьте день рождения ответом на его \
+        сообщение или перешлите мне его сообщение в этом формате.\n \
+        Другие события добавляются с видом события в начале, например 'work: Иван Иванов, 5 марта 2015' \
+        для годовщины работы. Виды событий: work, wedding, nameday, memorial.\n \
+        Теги добавляются хэштегами в конце, например 'Иван Иванов, 5 марта #backend #family'.\n \
+        Для выхода из режима обновления дней рождений введите команду /cancel",
+    add_reply_invalid_date: "Неверный формат ввода 😔 {error}. Ответьте на сообщение участника командой /add \
+        с датой, например /add 25-09 или /add 5 марта 1990",
+    remove: "Отправьте мне номер дня рождения, который хотите удалить. \n \
+        Для выхода из режима обновления дней рождений введите команду /cancel",
+    cancel: "Режим обновления дней рождений отключен. Для активации уведомлений выполните команду /active",
+    cancel_empty_list: "Режим обновления дней рождений отключен. Ни одного дня рождения не добавлено.",
+    cancel_already_disabled: "Режим обновления дней рождений уже отключен.",
+    active: "Уведомления от меня активны!🎉",
+    active_already_active: "Уведомления от меня уже активны!",
+    active_waiting_json: "Прежде чем активировать уведомления, отправьте мне заполненный JSON файл с днями \
+        рождения или выполните команду /cancel для выхода из режима обновления дней рождений",
+    active_empty_list: "Ни одного дня рождения не добавлено 😞, поэтому уведомления от меня не активны. \
+        Для добавления дней рождений выполните команду /add или /addmany",
+    active_waiting_birthday: "Прежде чем активировать уведомления, выполните команду /cancel, чтобы выйти из \
+        режима обновления дней рождений",
+    disable: "Уведомления от меня отключены!",
+    disable_already_disabled: "Уведомления от меня уже отключены!",
+    disable_waiting: "Прежде чем отключить уведомления, выполните команду /cancel для выхода из режима \
+        обновления дней рождений",
+    disable_empty_list: "Ни одного дня рождения не добавлено 😞, поэтому уведомления от меня не активны. \
+        Для добавления дней рождений выполните команду /add или /addmany",
+    privacy_enabled: "Список дней рождений скрыт от участников. Команды /next, /today, /month и /find \
+        доступны только администраторам 🔒",
+    privacy_disabled: "Список дней рождений открыт. Команды /next, /today, /month и /find доступны всем \
+        участникам 🔓",
+    self_registration_enabled: "Участники могут добавлять свои дни рождения командой /mybirthday 🔓",
+    self_registration_disabled: "Самостоятельное добавление дней рождений участниками отключено 🔒",
+    date_order_day_month: "Теперь в датах вида 05.03 или 5/3 сначала указывается день, а затем месяц \
+        (05.03 — 5 марта)",
+    date_order_month_day: "Теперь в датах вида 03.05 или 3/5 сначала указывается месяц, а затем день \
+        (3/5 — 5 марта)",
+    language_changed: "Теперь я говорю по-русски 🇷🇺",
+    language_usage: "Укажите язык, например /language en. Доступные языки: {languages}",
+    advance_enabled: "Теперь я буду напоминать о днях рождения за {days} {days_word} 🔔",
+    advance_disabled: "Напоминания о днях рождения заранее отключены 🔕",
+    advance_usage: "Укажите, за сколько дней напоминать о днях рождения, например /advance 3 (не больше \
+        {max}), или /advance 0, чтобы отключить напоминания заранее",
+    greetings_header: "Шаблоны поздравлений в день рождения:",
+    advance_greetings_header: "Шаблоны напоминаний заранее:",
+    event_greetings_header: "Шаблоны поздравлений ({event}):",
+    event_advance_greetings_header: "Шаблоны напоминаний заранее ({event}):",
+    greetings_default: "Свои шаблоны не заданы, используется стандартный:\n{template}",
+    greetings_usage: "Добавить шаблон: {command} текст шаблона\n\
+        Удалить шаблон: {command} remove номер\n\
+        Вернуть стандартный шаблон: {command} reset\n\
+        Доступные подстановки: {placeholders}. Если шаблонов несколько, я выбираю один из них случайно\n\
+        Шаблоны других событий задаются с видом события в начале, например /greeting work: текст шаблона. \
+        Виды событий: {kinds}",
+    greeting_added: "Шаблон №{index} добавлен! 🎉 Пример:",
+    greeting_removed: "Шаблон №{index} удалён",
+    greeting_not_found: "Шаблон не найден по указанному номеру 😔 Попробуйте ещё раз",
+    greetings_reset: "Свои шаблоны удалены, теперь используется стандартный",
+    greetings_too_many: "Можно задать не больше {max} шаблонов 😔 Удалите ненужные командой {command} remove номер",
+    invalid_template: "Шаблон не сохранён 😔 {error}",
+    combine_enabled: "Теперь все напоминания одного дня я буду присылать одним сообщением 📋",
+    combine_disabled: "Теперь я буду присылать отдельное сообщение для каждого дня рождения",
+    digest_usage: "Укажите, какую сводку включить или отключить:\n\
+        /digest weekly — по понедельникам, о днях рождения на неделе\n\
+        /digest monthly — первого числа, о днях рождения в месяце\n\n\
+        Сейчас еженедельная сводка {weekly}, ежемесячная сводка {monthly}",
+    digest_on: "включена",
+    digest_off: "отключена",
+    weekly_digest_enabled: "Теперь по понедельникам я буду присылать дни рождения на неделю 📅",
+    weekly_digest_disabled: "Еженедельная сводка дней рождений отключена",
+    monthly_digest_enabled: "Теперь первого числа я буду присылать дни рождения на месяц 📅",
+    monthly_digest_disabled: "Ежемесячная сводка дней рождений отключена",
+    surprise_enabled: "Режим сюрприза включён 🤫 Напоминания заранее я буду присылать только в личные сообщения \
+        участникам, подписавшимся командой /subscribe, кроме самого именинника. Поздравление в день рождения \
+        по-прежнему будет в группе",
+    surprise_disabled: "Режим сюрприза отключён, напоминания заранее снова будут приходить в группу",
+    name_days_enabled: "Теперь я буду поздравлять с именинами по православному календарю 😇 Именины ищутся \
+        по первому имени из списка, уменьшительные имена тоже подходят: Ваня празднует в дни Ивана",
+    name_days_disabled: "Поздравления с именинами по календарю отключены",
+    name_day_calendar_reminder: "😇 Сегодня именины празднуют: {names}! Поздравьте их 💐",
+    tags_usage: "Укажите номер дня рождения из /list и теги, например /tags 3 #backend #family. \
+        Отправьте /tags 3 без тегов, чтобы убрать теги",
+    tags_updated: "Теги {name}: {tags}",
+    tags_cleared: "Теги {name} удалены",
+    tag_rule_usage: "Укажите тег и правило напоминаний, например /tagrule #management advance. Правила:\n{rules}",
+    tag_rules_header: "Правила напоминаний по тегам:",
+    tag_rule_set: "Для тега #{tag}: {rule}",
+    delivery_all: "все напоминания как обычно",
+    delivery_advance: "только напоминания заранее, без поздравления в сам день",
+    delivery_private: "напоминания только подписчикам в личные сообщения",
+    delivery_off: "без напоминаний",
+    notes_usage: "Укажите номер дня рождения из /list, например /notes 3. Добавить заметку можно командой \
+        /note 3 текст, а подарок — командой /gift 3 2025 книга",
+    notes_header: "Заметки о {name} 📝",
+    notes_empty: "Заметок пока нет",
+    gifts_header: "История подарков 🎁",
+    gifts_empty: "Подарков пока нет",
+    notes_sent_privately: "Отправил заметки вам в личные сообщения 🤫",
+    notes_private_failed: "Не могу написать вам в личные сообщения 😔 Начните со мной личный чат и повторите команду",
+    note_usage: "Укажите номер дня рождения из /list и текст заметки, например /note 3 аллергия на орехи",
+    note_too_long: "Текст не должен быть длиннее {max} символов",
+    notes_too_many: "Можно сохранить не больше {max} заметок о человеке 😔",
+    note_added: "Заметка о {name} сохранена 📝 Посмотреть заметки можно командой /notes",
+    gift_usage: "Укажите номер дня рождения из /list, год (по умолчанию текущий) и подарок, например \
+        /gift 3 2025 книга",
+    gifts_too_many: "В истории можно сохранить не больше {max} подарков 😔",
+    private_not_hidden: "Я не могу удалить это сообщение, поэтому ничего не сохранил, чтобы его не видели все \
+        участники 🤫 Дайте мне право удалять сообщения, удалите это сообщение и повторите команду",
+    gift_added: "Подарок для {name} за {year} год сохранён 🎁",
+    topic_not_forum: "В этом чате нет тем. Команда нужна для супергрупп с темами форума",
+    topic_set: "Теперь напоминания, сводки и сборы на подарки я буду публиковать в этой теме 📌",
+    topic_reset: "Теперь напоминания, сводки и сборы на подарки я буду публиковать в общей теме",
+    pin_enabled: "Теперь я буду закреплять поздравление без уведомления и откреплять его на следующий день 📌",
+    pin_disabled: "Закрепление поздравлений отключено",
+    pin_failed: "Администраторы, я не смог закрепить поздравление: у меня нет права закреплять сообщения 😔 \
+        Закрепление отключено. Выдайте мне это право и включите закрепление снова командой /pin. \
+        Проверить мои права можно командой /checkcontrol",
+    topic_lost: "Администраторы, выбранная тема форума удалена или закрыта 😔 Теперь я публикую напоминания \
+        в общей теме. Чтобы выбрать другую тему, отправьте в ней команду /topic",
+    card_mode_off: "только текст поздравления",
+    card_mode_photo: "открытка с поздравлением в подписи вместо текста",
+    card_mode_both: "текст поздравления и открытка следом",
+    card_theme_confetti: "тёплый градиент с конфетти",
+    card_theme_night: "ночное небо со звёздами",
+    card_theme_pastel: "пастельные цвета с воздушными шарами",
+    card_theme_classic: "кремовый фон с золотой рамкой",
+    cards_usage: "Сейчас открытки: {mode}\n\nВыберите, как отправлять открытки в дни рождения, например \
+        /cards photo:\n{modes}",
+    cards_set: "Готово! Открытки в дни рождения: {mode} 🖼",
+    card_theme_usage: "Сейчас тема открыток: {theme}\n\nВыберите тему, например /cardtheme night:\n{themes}",
+    card_theme_set: "Готово! Тема открыток: {theme} 🎨 Посмотреть открытку можно командой /cardpreview",
+    card_preview_usage: "Укажите номер дня рождения из /list и, если хотите, тему, например /cardpreview 3 night. \
+        Без номера я нарисую открытку для вас",
+    card_preview_caption: "Так будет выглядеть открытка в теме «{theme}»",
+    card_preview_failed: "Не получилось нарисовать открытку 😔 Попробуйте позже",
+    card_title: "С днём рождения!",
+    card_age: "Исполняется {years}",
+    calendar_caption: "🗓 Дни рождения: {month} {year}",
+    calendar_failed: "Не получилось нарисовать календарь 😔 Попробуйте позже",
+    poll_usage: "Укажите номер дня рождения из /list, вопрос и варианты ответа через точку с запятой, например:\n\
+        /poll 3 Что дарим?; Книгу; Сертификат; Билеты в театр\n\n\
+        Опрос будет опубликован вместе с напоминанием заранее, а за день до дня рождения я подведу итоги. \
+        Отменить опрос можно командой /cancelpoll номер",
+    poll_birthday_not_found: "День рождения не найден 😔 Проверьте номер в /list",
+    poll_too_soon: "День рождения {name} уже совсем скоро 😔 Итоги опроса подводятся за день до дня рождения, \
+        так что провести его уже не получится",
+    poll_surprise: "Включён режим сюрприза 🤫 Опрос увидел бы и именинник, поэтому в этом режиме опросы не публикуются. \
+        Отключить режим можно командой /surprise",
+    poll_exists: "Опрос о дне рождения {name} уже есть. Отмените его командой /cancelpoll, чтобы создать новый",
+    polls_too_many: "Можно создать не больше {max} опросов 😔 Отмените ненужные командой /cancelpoll номер",
+    poll_too_few_options: "Добавьте хотя бы {min} варианта ответа через точку с запятой",
+    poll_too_many_options: "Можно указать не больше {max} вариантов ответа 😔",
+    poll_question_too_long: "Вопрос слишком длинный 😔 Сократите его до {max} символов",
+    poll_option_too_long: "Вариант «{option}» слишком длинный 😔 Сократите его до {max} символов",
+    poll_added: "Готово! Опрос №{id} о дне рождения {name} будет опубликован вместе с напоминанием заранее 🗳",
+    poll_needs_advance: "⚠️ Опрос публикуется вместе с напоминанием заранее, если до дня рождения остаётся хотя бы \
+        2 дня, а сейчас напоминания заранее выключены или приходят позже. Настройте их, например, командой /advance 7",
+    polls_header: "Опросы к дням рождения:",
+    poll_posted: "опубликован",
+    poll_waiting: "ждёт напоминания",
+    poll_cancelled: "Опрос о дне рождения {name} отменён",
+    poll_not_found: "Опрос не найден по указанному номеру 😔 Номера опросов можно посмотреть командой /poll",
+    poll_summary: "🗳 Итоги опроса «{question}» ко дню рождения {name}:\n\n{results}\n{verdict}",
+    votes: ["голос", "голоса", "голосов"],
+    poll_winner: "Побеждает вариант «{option}» 🎉",
+    poll_tie: "Голоса разделились поровну: {options}",
+    poll_no_votes: "Никто не проголосовал 😔",
+    collection_usage: "Укажите номер дня рождения из /list или имя и сумму сбора, например /collect 3 5000 \
+        или /collect Иван 5000",
+    collection_birthday_not_found: "День рождения не найден 😔 Проверьте номер или имя в /list",
+    collection_ambiguous: "По запросу «{query}» нашлось несколько дней рождений, укажите номер из /list",
+    collection_exists: "Сбор на подарок для {name} уже открыт",
+
+    collection_text: "💰 Сбор на подарок для {name} ({date})\n\
+        Цель: {target}\n\
+        Организатор: {organizer}\n\n\
+        Сдали ({count}):\n{contributors}\n\
+        Нажмите «Я сдал(а)», когда переведёте деньги организатору",
+    collection_closed_text: "✅ Сбор на подарок для {name} ({date}) закрыт\n\
+        Цель: {target}\n\
+        Организатор: {organizer}\n\n\
+        Сдали ({count}):\n{contributors}",
+    collection_no_contributors: "пока никто\n",
+    collection_paid_button: "Я сдал(а) 💸",
+    collection_close_button: "Закрыть сбор 🔒",
+    collection_paid: "Спасибо! Вы отмечены в списке сдавших 💸",
+    collection_unpaid: "Отметка о сдаче денег снята",
+    collection_closed: "Сбор закрыт",
+    collection_close_forbidden: "Закрыть сбор может только организатор или администратор",
+    collection_not_found: "Этот сбор уже закрыт",
+    event_usage: "Укажите, когда напомнить о событии, и через запятую его название, например:\n\
+        /event 2026-12-20, Прощальная вечеринка\n\
+        /event каждую пятницу, Планёрка\n\
+        /event каждую первую пятницу, Обед с командой\n\
+        /event каждую последнюю пятницу, Ретроспектива\n\
+        /event каждое 15 число, Сдать отчёты",
+    invalid_event: "Событие не добавлено 😔 {error}",
+    events_too_many: "Можно запланировать не больше {max} событий 😔 Отмените ненужные командой /cancelevent номер",
+    event_added: "Событие №{id} запланировано! 📅 Ближайшее напоминание: {date}",
+    events_empty: "Запланированных событий нет. Добавьте событие командой /event",
+    events_header: "Запланированные события:",
+    event_next: "ближайшее: {date}",
+    event_cancelled: "Событие «{title}» отменено",
+    event_not_found: "Событие не найдено по указанному номеру 😔 Номера событий можно посмотреть командой /events",
+    scheduled_event_reminder: "📅 Сегодня: {title}",
+    recurrence_once: "однократно, {date}",
+    recurrence_weekly: "еженедельно, {weekday}",
+    recurrence_monthly_day: "ежемесячно, {day}-го числа",
+    recurrence_monthly_weekday: "ежемесячно, {weekday} на {week} неделе",
+    recurrence_last_weekday: "ежемесячно, {weekday} на последней неделе",
+    weekdays: [
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+        "воскресенье",
+    ],
+    weekdays_short: ["Пн", "Вт", "Ср", "Чт", "Пт", "Сб", "Вс"],
+    week_ordinals: ["1-й", "2-й", "3-й", "4-й"],
+
+    invalid_format: "Неверный формат ввода 😔 Попробуйте ещё раз",
+    invalid_input: "Неверный формат ввода 😔 {error}. Попробуйте ещё раз",
+    birthday_added: "День рождения успешно добавлен! 🎉",
+    too_many_lines: "Слишком много строк в одном сообщении 😔 Отправьте не более {max} дней рождений за раз",
+    line_added: "✅ {index}. {name} — {date}",
+    line_failed: "❌ {index}. «{line}»: {error}",
+    lines_summary: "Добавлено дней рождений: {added} из {total}",
+    birthdays_loaded: "Дни рождения успешно загружены! 🎉",
+    invalid_file: "К сожалению, отправленный файл не корректный или содержит ошибки😔 \
+        Проверьте его и отправьте ещё раз",
+    birthday_removed: "День рождения, Имя: {name}, Дата: {date} успешно удалён!",
+    birthday_not_found: "День рождения не найден по указанному индексу 😔 Попробуйте ещё раз",
+
+    private_list: "Администраторы скрыли список дней рождений от участников этого чата 🔒",
+    invalid_count: "Неверный формат ввода 😔 Укажите количество дней рождений числом, например /next 10",
+    invalid_month: "Неверный формат ввода 😔 Укажите месяц названием или номером, например /month март \
+        или /month 3",
+    empty_query: "Укажите имя или username для поиска, например /find Иван или /find @ivan",
+    invalid_date: "Неверный формат ввода 😔 {error}. Например, /mybirthday 25-09 или /mybirthday 5 марта 1990",
+    group_only: "Эта команда доступна только в группах",
+    not_active: "Уведомления о днях рождениях в этом чате не активны, поэтому добавить свой день рождения \
+        нельзя 😞",
+    self_registration_forbidden: "Администраторы отключили самостоятельное добавление дней рождений в этом \
+        чате 🔒",
+    not_registered: "Вашего дня рождения нет в списке этого чата",
+    next_header: "Ближайшие дни рождения:",
+    today: "сегодня! 🎉",
+    days_left: "через {days} {days_word}",
+    today_header: "Сегодня празднуют:",
+    memorial_header: "Сегодня вспоминаем:",
+    no_birthdays_today: "Сегодня никто не празднует день рождения",
+    month_empty: "{month}: дней рождений нет",
+    find_nothing: "По запросу «{query}» ничего не найдено",
+    find_header: "Найдено по запросу «{query}»:",
+    my_birthday_added: "Ваш день рождения успешно добавлен! 🎉",
+    my_birthday_updated: "Ваш день рождения успешно обновлён! 🎉",
+    my_birthday_removed: "Ваш день рождения успешно удалён из списка",
+    private_only: "Эта команда доступна только в личных сообщениях со мной",
+    subscribe_usage: "Отправьте /subscribe в группе, напоминания из которой хотите получать в личные сообщения",
+    subscribe_inactive: "В этой группе уведомления о днях рождения не включены 😔 Администраторы могут включить их \
+        командой /active",
+    subscribe_start_private: "Я не могу написать вам в личные сообщения 😔 Начните личный чат со мной, нажав «Старт», \
+        и отправьте /subscribe здесь ещё раз",
+    subscribed_in_group: "Готово! Подтверждение подписки я отправил вам в личные сообщения 🔔",
+    unsubscribe_list_header: "Ваши подписки на напоминания:",
+    unsubscribe_usage: "Отправьте /unsubscribe номер, чтобы отписаться от напоминаний группы",
+    no_subscriptions: "У вас нет подписок на напоминания",
+    subscription_not_found: "Группа не найдена по указанному номеру 😔 Попробуйте ещё раз",
+    subscribed: "Теперь я буду присылать вам напоминания о днях рождения из группы «{chat}» 🔔",
+    already_subscribed: "Вы уже подписаны на напоминания из группы «{chat}»",
+    unsubscribed: "Вы отписались от напоминаний из группы «{chat}» 🔕",
+    subscription_header: "🔔 Напоминание из группы «{chat}»:",
+    my_wishlist_header: "Ваш список желаний 🎁",
+    my_wishlist_empty: "Список пуст",
+    my_wishlist_usage: "Отправьте /mywishlist текст, чтобы добавить желание (не больше {max}), \
+        /mywishlist remove номер, чтобы удалить его, или /mywishlist clear, чтобы очистить список. \
+        Желания, которые кто-то уже выбрал, скрыты от вас, чтобы подарок остался сюрпризом",
+    wishlist_item_added: "Желание добавлено в список 🎁",
+    wishlist_item_removed: "Желание {index} удалено из списка",
+    wishlist_item_index_not_found: "Желание не найдено по указанному номеру 😔 Попробуйте ещё раз",
+    wishlist_cleared: "Список желаний очищен",
+    wishlist_too_many: "В списке не может быть больше {max} желаний",
+    wishlist_item_too_long: "Желание не должно быть длиннее {max} символов",
+    wishlist_usage: "Укажите имя или username участника, например /wishlist Иван",
+    wishlist_not_found: "Не нашёл участника «{query}» со списком желаний 😔",
+    wishlist_header: "Список желаний {name} 🎁",
+    wishlist_empty: "В списке желаний {name} пока ничего нет",
+    wishlist_hint: "Нажмите на желание, чтобы выбрать его подарком. Выбранные желания скрываются из списка",
+    wishlist_claim_button: "🎁 {index}. {item}",
+    wishlist_show_button: "🎁 Список желаний {name}",
+    wishlist_claimed: "Вы выбрали подарок «{item}». Нажмите ещё раз, чтобы отменить выбор",
+    wishlist_unclaimed: "Вы отменили выбор подарка «{item}»",
+    wishlist_already_claimed: "Этот подарок уже выбрал кто-то другой",
+    wishlist_own_claim: "Нельзя выбрать подарок из своего списка желаний 🙂",
+    wishlist_item_not_found: "Этого желания больше нет в списке",
+
+    parse_missing_separator: "Отделите имя от даты запятой, например «Иван Иванов, 5 марта»",
+    parse_empty_name: "Не указано имя",
+    parse_name_too_long: "Имя не должно быть длиннее {max} символов",
+    parse_invalid_name_char: "Имя содержит недопустимый символ «{char}»",
+    parse_missing_date: "Не указана дата",
+    parse_unknown_date: "Не удалось распознать дату «{date}». Используйте, например, «5 марта», «March 5», \
+        «05.03» или «05-03»",
+    parse_unknown_month: "Не удалось распознать месяц «{month}»",
+    parse_invalid_month: "Месяца с номером {month} не существует",
+    parse_invalid_day: "Дня {day}.{month} не существует",
+    parse_short_year: "Укажите год «{year}» четырьмя цифрами",
+    parse_invalid_year: "Год рождения {year} должен быть не раньше {min} и не позже текущего",
+    parse_invalid_username: "«{username}» не похоже на username в Telegram. Username может содержать только \
+        латинские буквы, цифры и символ подчёркивания",
+    parse_unknown_kind: "Неизвестный вид события «{kind}». Доступные виды: {kinds}",
+    parse_invalid_tag: "«{tag}» не подходит для тега. Тег начинается с # и может содержать только буквы, цифры, \
+        символы _ и -, не длиннее {max} символов",
+
+    schedule_missing_separator: "Отделите дату или правило повтора от названия запятой, например \
+        «каждую пятницу, Планёрка»",
+    schedule_empty_title: "Не указано название события",
+    schedule_title_too_long: "Название события не должно быть длиннее {max} символов",
+    schedule_past_date: "Эта дата уже прошла, или напоминания на неё уже отправлены",
+    schedule_unknown_rule: "Не удалось распознать «{rule}». Укажите дату, например 2026-12-20 или 20.12.2026, \
+        или правило повтора, например «каждую пятницу», «каждую первую пятницу», «каждую последнюю пятницу» \
+        или «каждое 15 число»",
+
+    template_empty: "Шаблон не может быть пустым",
+    template_too_long: "Шаблон не должен быть длиннее {max} символов",
+    template_unknown_placeholder: "Неизвестная подстановка {{placeholder}}. Доступные подстановки: {placeholders}",
+    template_unclosed_brace: "Каждая фигурная скобка должна быть парной, например {name}",
+    template_no_person: "Шаблон должен содержать {name} или {mention}, чтобы было понятно, кого поздравлять",
+};
+
+/// The English messages.
+pub const EN: Messages = Messages {
+    command_descriptions: &[
+        ("start", "Displays the welcome message"),
+        ("help", "Displays this message"),
+        ("checkcontrol", "Checks your permissions"),
+        ("file", "Ask me to send you a sample filled JSON file"),
+        (
+            "next",
+            "Displays the upcoming birthdays, e.g. /next 10 or /next 10 #backend",
+        ),
+        ("today", "Displays today's birthdays"),
+        ("month", "Displays the birthdays in a month, e.g. /month march"),
+        ("find", "Searches birthdays by name or username, e.g. /find John"),
+        (
+            "mybirthday",
+            "Adds or updates your birthday, e.g. /mybirthday 25-09 or /mybirthday 25-09-1990",
+        ),
+        ("forgetme", "Removes your birthday from the list"),
+        (
+            "subscribe",
+            "Subscribes you to the reminders of a group in private messages: send it in the group",
+        ),
+        (
+            "unsubscribe",
+            "Unsubscribes you from the reminders of a group in private messages",
+        ),
+        (
+            "mywishlist",
+            "Shows or changes your wishlist, e.g. /mywishlist a book",
+        ),
+        ("wishlist", "Displays a member's wishlist, e.g. /wishlist John"),
+        (
+            "add",
+            "Enables the adding mode. Reply /add DD-MM to a member's message to add their birthday",
+        ),
+        (
+            "addmany",
+            "Enables the mode of adding multiple birthdays to the list using a JSON file",
+        ),
+        ("remove", "Enables the removing mode"),
+        ("cancel", "Disables the adding or removing mode"),
+        ("active", "Enables birthday notifications from me"),
+        ("disable", "Disables birthday notifications from me"),
+        (
+            "list",
+            "Displays the list of birthdays and other events, e.g. /list work or /list #backend",
+        ),
+        (
+            "export",
+            "Sends the events in a JSON file to load with /addmany, e.g. /export #backend",
+        ),
+        ("privacy", "Hides or shows the list of birthdays for members"),
+        (
+            "selfregistration",
+            "Allows or forbids members to add their own birthdays",
+        ),
+        (
+            "dateorder",
+            "Switches the order of the day and month in dates like 05.03 or 5/3",
+        ),
+        ("language", "Changes my language, e.g. /language en"),
+        (
+            "advance",
+            "Sets how many days before a birthday to remind about it, e.g. /advance 3",
+        ),
+        (
+            "greeting",
+            "Shows or changes the birthday greeting templates, e.g. /greeting Happy birthday, {mention}!",
+        ),
+        (
+            "advancegreeting",
+            "Shows or changes the templates of the reminders sent in advance",
+        ),
+        (
+            "digest",
+            "Enables or disables the weekly or monthly birthday digest, e.g. /digest weekly",
+        ),
+        (
+            "surprise",
+            "Enables or disables the surprise mode: only subscribers get the advance reminders",
+        ),
+        (
+            "namedays",
+            "Enables or disables name day announcements from the Orthodox calendar",
+        ),
+        (
+            "collect",
+            "Opens a gift money collection, e.g. /collect 3 5000, where 3 is the number from /list",
+        ),
+        (
+            "combine",
+            "Combines the reminders of one day into a single message or splits them",
+        ),
+        (
+            "event",
+            "Schedules a one-off or recurring event, e.g. /event every first friday, Team lunch",
+        ),
+        ("events", "Displays the list of scheduled events"),
+        (
+            "cancelevent",
+            "Cancels a scheduled event, e.g. /cancelevent 2",
+        ),
+        (
+            "tags",
+            "Sets the tags of a birthday, e.g. /tags 3 #backend, where 3 is the number from /list",
+        ),
+        (
+            "tagrule",
+            "Sets the reminder rule of a tag, e.g. /tagrule #management advance",
+        ),
+        (
+            "notes",
+            "Sends the notes and the gift history in private messages, e.g. /notes 3",
+        ),
+        (
+            "note",
+            "Adds a note about a person, e.g. /note 3 allergic to nuts",
+        ),
+        ("gift", "Adds a gift to the history, e.g. /gift 3 2025 a book"),
+        (
+            "topic",
+            "Chooses the forum topic for the reminders: send the command in that topic",
+        ),
+        ("pin", "Enables or disables pinning the greeting for the day"),
+        (
+            "cards",
+            "Chooses how to send the birthday cards, e.g. /cards photo",
+        ),
+        (
+            "cardtheme",
+            "Chooses the theme of the birthday cards, e.g. /cardtheme night",
+        ),
+        (
+            "cardpreview",
+            "Shows a birthday card, e.g. /cardpreview 3, where 3 is the number from /list",
+        ),
+        (
+            "calendar",
+            "Draws the birthday calendar of a month, e.g. /calendar march",
+        ),
+        (
+            "poll",
+            "Attaches a poll to a birthday, e.g. /poll 3 What shall we give?; A book; Flowers",
+        ),
+        ("cancelpoll", "Cancels a birthday poll, e.g. /cancelpoll 2"),
+        ("status", "Checks the bot status"),
+        ("backup", "Starts the backup of the bot data"),
+    ],
+    commands_header: "You can interact with me and manage me using these commands.🤖\n\n\
+        The main commands are available only to administrators of groups and channels, or if you added me to a chat.\n\
+        You can check your status with the /checkcontrol command",
+    greetings: "Hi! This bot is made for those who keep forgetting about birthdays😁\n
+With it, you will never forget to congratulate your friends, colleagues or relatives.\n
+Use the /help command for more details on the setup.",
+    creator: "You are my creator!🙏",
+    admin_interaction: "You can interact with me {place}!😄",
+    no_interaction: "Unfortunately, you can't interact with me {place}😞",
+    pin_rights_granted: "📌 I can pin messages in this chat",
+    pin_rights_missing: "⚠️ I'm not allowed to pin messages, so the greetings and gift collections won't be \
+        pinned. Make me an administrator with the right to pin messages",
+    place_group: "in the group",
+    place_channel: "in the channel",
+    place_chat: "in the chat",
+    busy: "Unfortunately, I can't accept new requests right now due to high load 😞 Please try again later",
+    list_empty: "The list of birthdays is empty",
+    list_header: "The list of birthdays:",
+    list_usage: "Specify the kind of events, e.g. /list work, and/or a tag, e.g. /list #backend, or send /list \
+        without arguments to see all events. The kinds of events: {kinds}",
+    export_usage: "Specify the tags, e.g. /export #backend, or send /export without arguments to export all \
+        events. The notes and gifts are exported only with /export notes",
+    export_failed: "I couldn't prepare the file 😔 Try again later",
+    reminder: "Today is the birthday of a wonderful person, {mention}! Don't forget to congratulate!🎉",
+    advance_reminder: "In {days_left} it's {mention}'s birthday! Don't forget to prepare a greeting 🎁",
+    work_anniversary_reminder: "Today is {mention}'s work anniversary! Thank you for being with us 💼",
+    wedding_reminder: "Today is {mention}'s wedding anniversary! Congratulations 💍",
+    name_day_reminder: "Today is {mention}'s name day! Don't forget to congratulate 😇",
+    memorial_reminder: "Today is a memorial date: {name} 🕯",
+    event_advance_reminder: "In {days_left}: {event} of {mention} 🔔",
+    digest_advance_header: "In {days_left}:",
+    digest_footer: "Don't forget to congratulate! 🎉",
+    weekly_digest_header: "Birthdays this week:",
+    monthly_digest_header: "Birthdays this month ({month}):",
+    month_names: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    days: ["day", "days", "days"],
+    years: ["year", "years", "years"],
+    event_birthday: "birthday",
+    event_work_anniversary: "work anniversary",
+    event_wedding: "wedding anniversary",
+    event_name_day: "name day",
+    event_memorial: "memorial date",
+
+    json: "Send me a filled JSON file with the birthdays. I've sent you a sample of what the file should look like",
+    add: "Send me a birthday in the format 'Name Surname, date, @username' or 'Name Surname, date'. \
+        The date can be written as 'March 5', '5 марта', '05.03', '5/3' or '05-03', optionally with the year, \
+        e.g. 'John Smith, March 5 1990, @john' or 'Mary-Jane Watson, 05.03'.\n \
+        You can send several birthdays at once, one per line.\n \
+        To have the person mentioned in the greeting even without a username, send the birthday as a reply to \
+        their message or forward me their message in this format.\n \
+        Other events are added with the kind of the event at the start, e.g. 'work: John Smith, March 5 2015' \
+        for a work anniversary. The kinds of events: work, wedding, nameday, memorial.\n \
+        Tags are added as hashtags at the end, e.g. 'John Smith, March 5 #backend #family'.\n \
+        To exit the birthday editing mode, use the /cancel command",
+    add_reply_invalid_date: "Invalid input format 😔 {error}. Reply to a member's message with /add and the date, \
+        e.g. /add 25-09 or /add March 5 1990",
+    remove: "Send me the number of the birthday you want to remove. \n \
+        To exit the birthday editing mode, use the /cancel command",
+    cancel: "The birthday editing mode is disabled. To enable notifications, use the /active command",
+    cancel_empty_list: "The birthday editing mode is disabled. No birthdays have been added.",
+    cancel_already_disabled: "The birthday editing mode is already disabled.",
+    active: "Notifications from me are active!🎉",
+    active_already_active: "Notifications from me are already active!",
+    active_waiting_json: "Before enabling notifications, send me a filled JSON file with birthdays or use the \
+        /cancel command to exit the birthday editing mode",
+    active_empty_list: "No birthdays have been added 😞, so notifications from me are not active. \
+        To add birthdays, use the /add or /addmany command",
+    active_waiting_birthday: "Before enabling notifications, use the /cancel command to exit the birthday \
+        editing mode",
+    disable: "Notifications from me are disabled!",
+    disable_already_disabled: "Notifications from me are already disabled!",
+    disable_waiting: "Before disabling notifications, use the /cancel command to exit the birthday editing mode",
+    disable_empty_list: "No birthdays have been added 😞, so notifications from me are not active. \
+        To add birthdays, use the /add or /addmany command",
+    privacy_enabled: "The list of birthdays is hidden from members. The /next, /today, /month and /find \
+        commands are available only to administrators 🔒",
+    privacy_disabled: "The list of birthdays is visible. The /next, /today, /month and /find commands are \
+        available to all members 🔓",
+    self_registration_enabled: "Members can add their own birthdays with the /mybirthday command 🔓",
+    self_registration_disabled: "Members can no longer add their own birthdays 🔒",
+    date_order_day_month: "Now the day goes first in dates like 05.03 or 5/3 (05.03 is March 5)",
+    date_order_month_day: "Now the month goes first in dates like 03.05 or 3/5 (3/5 is March 5)",
+    language_changed: "Now I speak English 🇬🇧",
+    language_usage: "Specify the language, e.g. /language ru. Available languages: {languages}",
+    advance_enabled: "Now I will remind about birthdays {days} {days_word} in advance 🔔",
+    advance_disabled: "Advance birthday reminders are disabled 🔕",
+    advance_usage: "Specify how many days in advance to remind about birthdays, e.g. /advance 3 (no more than \
+        {max}), or /advance 0 to disable advance reminders",
+    greetings_header: "Birthday greeting templates:",
+    advance_greetings_header: "Advance reminder templates:",
+    event_greetings_header: "Greeting templates ({event}):",
+    event_advance_greetings_header: "Advance reminder templates ({event}):",
+    greetings_default: "No custom templates are set, the default one is used:\n{template}",
+    greetings_usage: "Add a template: {command} template text\n\
+        Remove a template: {command} remove number\n\
+        Restore the default template: {command} reset\n\
+        Available placeholders: {placeholders}. If there are several templates, I pick one of them at random\n\
+        The templates of other events are set with the kind of the event at the start, e.g. \
+        /greeting work: template text. The kinds of events: {kinds}",
+    greeting_added: "Template #{index} has been added! 🎉 Example:",
+    greeting_removed: "Template #{index} has been removed",
+    greeting_not_found: "No template found with the given number 😔 Please try again",
+    greetings_reset: "Custom templates have been removed, the default one is used now",
+    greetings_too_many: "You can set no more than {max} templates 😔 Remove unneeded ones with {command} remove number",
+    invalid_template: "The template has not been saved 😔 {error}",
+    combine_enabled: "Now I will send all reminders of one day in a single message 📋",
+    combine_disabled: "Now I will send a separate message for each birthday",
+    digest_usage: "Specify which digest to enable or disable:\n\
+        /digest weekly — on Mondays, about the birthdays of the week\n\
+        /digest monthly — on the first day of the month, about the birthdays of the month\n\n\
+        Now the weekly digest is {weekly} and the monthly digest is {monthly}",
+    digest_on: "enabled",
+    digest_off: "disabled",
+    weekly_digest_enabled: "Now I will send the birthdays of the week on Mondays 📅",
+    weekly_digest_disabled: "The weekly birthday digest is disabled",
+    monthly_digest_enabled: "Now I will send the birthdays of the month on the first day of the month 📅",
+    monthly_digest_disabled: "The monthly birthday digest is disabled",
+    surprise_enabled: "Surprise mode is enabled 🤫 I will send advance reminders only in private messages to the \
+        members who subscribed with the /subscribe command, except for the birthday person. The birthday greeting \
+        will still be posted in the group",
+    surprise_disabled: "Surprise mode is disabled, advance reminders will be posted in the group again",
+    name_days_enabled: "From now on I will announce name days according to the Orthodox calendar 😇 Name days \
+        are found by the first name in the list, diminutives work as well: Ваня celebrates on the days of Иван",
+    name_days_disabled: "Name day announcements from the calendar are disabled",
+    name_day_calendar_reminder: "😇 Today is the name day of: {names}! Congratulate them 💐",
+    tags_usage: "Specify the number of the birthday from /list and the tags, e.g. /tags 3 #backend #family. \
+        Send /tags 3 without tags to remove the tags",
+    tags_updated: "{name}'s tags: {tags}",
+    tags_cleared: "{name}'s tags have been removed",
+    tag_rule_usage: "Specify the tag and the reminder rule, e.g. /tagrule #management advance. The rules:\n{rules}",
+    tag_rules_header: "Reminder rules by tags:",
+    tag_rule_set: "For the tag #{tag}: {rule}",
+    delivery_all: "all reminders as usual",
+    delivery_advance: "only advance reminders, no greeting on the day",
+    delivery_private: "reminders only to the subscribers in private messages",
+    delivery_off: "no reminders",
+    notes_usage: "Specify the number of the birthday from /list, e.g. /notes 3. Add a note with /note 3 text \
+        and a gift with /gift 3 2025 a book",
+    notes_header: "Notes about {name} 📝",
+    notes_empty: "No notes yet",
+    gifts_header: "Gift history 🎁",
+    gifts_empty: "No gifts yet",
+    notes_sent_privately: "I've sent you the notes in private messages 🤫",
+    notes_private_failed: "I can't send you private messages 😔 Start a private chat with me and repeat the command",
+    note_usage: "Specify the number of the birthday from /list and the text of the note, e.g. /note 3 allergic to nuts",
+    note_too_long: "The text must not be longer than {max} characters",
+    notes_too_many: "You can save no more than {max} notes about a person 😔",
+    note_added: "The note about {name} has been saved 📝 The notes are shown by /notes",
+    gift_usage: "Specify the number of the birthday from /list, the year (the current one by default) and the gift, \
+        e.g. /gift 3 2025 a book",
+    gifts_too_many: "You can save no more than {max} gifts in the history 😔",
+    private_not_hidden: "I can't delete this message, so I haven't saved anything to keep it from all the \
+        members 🤫 Give me the right to delete messages, delete this message and repeat the command",
+    gift_added: "The gift for {name} in {year} has been saved 🎁",
+    topic_not_forum: "This chat has no topics. The command is meant for supergroups with forum topics",
+    topic_set: "From now on I will post the reminders, digests and gift collections in this topic 📌",
+    topic_reset: "From now on I will post the reminders, digests and gift collections in the General topic",
+    pin_enabled: "From now on I will silently pin the greeting and unpin it the next day 📌",
+    pin_disabled: "Pinning the greetings is disabled",
+    pin_failed: "Admins, I couldn't pin the greeting because I'm not allowed to pin messages 😔 Pinning is \
+        disabled. Grant me the right and enable pinning again with /pin. You can check my rights with \
+        /checkcontrol",
+    topic_lost: "Admins, the chosen forum topic is deleted or closed 😔 I post the reminders in the General \
+        topic now. To choose another topic, send the /topic command in it",
+    card_mode_off: "only the text greeting",
+    card_mode_photo: "a card with the greeting in the caption instead of the text",
+    card_mode_both: "the text greeting followed by a card",
+    card_theme_confetti: "a warm gradient with confetti",
+    card_theme_night: "a night sky with stars",
+    card_theme_pastel: "pastel colors with balloons",
+    card_theme_classic: "a cream background with a golden frame",
+    cards_usage: "Birthday cards now: {mode}\n\nChoose how to send the cards on birthdays, e.g. /cards photo:\n{modes}",
+    cards_set: "Done! Birthday cards: {mode} 🖼",
+    card_theme_usage: "The card theme now: {theme}\n\nChoose a theme, e.g. /cardtheme night:\n{themes}",
+    card_theme_set: "Done! The card theme: {theme} 🎨 You can preview a card with /cardpreview",
+    card_preview_usage: "Specify the number of the birthday from /list and optionally the theme, e.g. \
+        /cardpreview 3 night. Without the number, I will draw a card for you",
+    card_preview_caption: "This is how the card looks in the «{theme}» theme",
+    card_preview_failed: "I couldn't draw the card 😔 Please try again later",
+    card_title: "Happy birthday!",
+    card_age: "Turning {age}",
+    calendar_caption: "🗓 Birthdays: {month} {year}",
+    calendar_failed: "I couldn't draw the calendar 😔 Please try again later",
+    poll_usage: "Specify the number of the birthday from /list, the question and the options separated by semicolons, e.g.:\n\
+        /poll 3 What shall we give?; A book; A gift card; Theatre tickets\n\n\
+        The poll will be posted with the advance reminder, and I will summarize the results the day before the birthday. \
+        You can cancel a poll with /cancelpoll number",
+    poll_birthday_not_found: "The birthday is not found 😔 Check the number in /list",
+    poll_too_soon: "The birthday of {name} is too soon 😔 The results are summarized the day before the birthday, \
+        so there is no time left for a poll",
+    poll_surprise: "The surprise mode is on 🤫 The birthday person would see the poll, so polls are not posted in this \
+        mode. You can turn the mode off with /surprise",
+    poll_exists: "There is already a poll about the birthday of {name}. Cancel it with /cancelpoll to create a new one",
+    polls_too_many: "You can create no more than {max} polls 😔 Cancel unneeded ones with /cancelpoll number",
+    poll_too_few_options: "Add at least {min} options separated by semicolons",
+    poll_too_many_options: "You can specify no more than {max} options 😔",
+    poll_question_too_long: "The question is too long 😔 Shorten it to {max} characters",
+    poll_option_too_long: "The option «{option}» is too long 😔 Shorten it to {max} characters",
+    poll_added: "Done! Poll #{id} about the birthday of {name} will be posted with the advance reminder 🗳",
+    poll_needs_advance: "⚠️ The poll is posted with the advance reminder if there are at least 2 days left before \
+        the birthday, but the advance reminders are disabled or come later now. Set them up, e.g. with /advance 7",
+    polls_header: "Birthday polls:",
+    poll_posted: "posted",
+    poll_waiting: "waiting for the reminder",
+    poll_cancelled: "The poll about the birthday of {name} has been cancelled",
+    poll_not_found: "No poll found with the given number 😔 The numbers of the polls are shown by /poll",
+    poll_summary: "🗳 The results of the poll «{question}» for the birthday of {name}:\n\n{results}\n{verdict}",
+    votes: ["vote", "votes", "votes"],
+    poll_winner: "The winner is «{option}» 🎉",
+    poll_tie: "It's a tie: {options}",
+    poll_no_votes: "Nobody has voted 😔",
+    collection_usage: "Specify the number of the birthday from /list or the name and the amount to collect, \
+        e.g. /collect 3 5000 or /collect John 5000",
+    collection_birthday_not_found: "The birthday is not found 😔 Check the number or the name in /list",
+    collection_ambiguous: "Several birthdays found for «{query}», specify the number from /list",
+    collection_exists: "A gift collection for {name} is already open",
+
+    collection_text: "💰 Gift collection for {name} ({date})\n\
+        Target: {target}\n\
+        Organizer: {organizer}\n\n\
+        Paid ({count}):\n{contributors}\n\
+        Press «I paid» once you have sent the money to the organizer",
+    collection_closed_text: "✅ Gift collection for {name} ({date}) is closed\n\
+        Target: {target}\n\
+        Organizer: {organizer}\n\n\
+        Paid ({count}):\n{contributors}",
+    collection_no_contributors: "nobody yet\n",
+    collection_paid_button: "I paid 💸",
+    collection_close_button: "Close the collection 🔒",
+    collection_paid: "Thank you! You are marked as paid 💸",
+    collection_unpaid: "The payment mark is removed",
+    collection_closed: "The collection is closed",
+    collection_close_forbidden: "Only the organizer or an administrator can close the collection",
+    collection_not_found: "This collection is already closed",
+    event_usage: "Specify when to remind about the event and its title after a comma, e.g.:\n\
+        /event 2026-12-20, Farewell party\n\
+        /event every friday, Weekly sync\n\
+        /event every first friday, Team lunch\n\
+        /event every last friday, Retrospective\n\
+        /event every 15, Submit the reports",
+    invalid_event: "The event has not been added 😔 {error}",
+    events_too_many: "You can schedule no more than {max} events 😔 Cancel unneeded ones with /cancelevent number",
+    event_added: "Event #{id} has been scheduled! 📅 The next reminder: {date}",
+    events_empty: "There are no scheduled events. Add an event with the /event command",
+    events_header: "Scheduled events:",
+    event_next: "next: {date}",
+    event_cancelled: "The event «{title}» has been cancelled",
+    event_not_found: "No event found with the given number 😔 The numbers of the events are shown by /events",
+    scheduled_event_reminder: "📅 Today: {title}",
+    recurrence_once: "once, {date}",
+    recurrence_weekly: "every {weekday}",
+    recurrence_monthly_day: "every month on day {day}",
+    recurrence_monthly_weekday: "every {week} {weekday} of the month",
+    recurrence_last_weekday: "every last {weekday} of the month",
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    week_ordinals: ["first", "second", "third", "fourth"],
+
+    invalid_format: "Invalid input format 😔 Please try again",
+    invalid_input: "Invalid input format 😔 {error}. Please try again",
+    birthday_added: "The birthday has been added! 🎉",
+    too_many_lines: "Too many lines in one message 😔 Send no more than {max} birthdays at once",
+    line_added: "✅ {index}. {name} — {date}",
+    line_failed: "❌ {index}. «{line}»: {error}",
+    lines_summary: "Birthdays added: {added} of {total}",
+    birthdays_loaded: "The birthdays have been loaded! 🎉",
+    invalid_file: "Unfortunately, the file you sent is invalid or contains errors😔 Please check it and send it again",
+    birthday_removed: "The birthday, Name: {name}, Date: {date} has been removed!",
+    birthday_not_found: "No birthday found with the given number 😔 Please try again",
+
+    private_list: "The administrators have hidden the list of birthdays from members of this chat 🔒",
+    invalid_count: "Invalid input format 😔 Specify the number of birthdays, e.g. /next 10",
+    invalid_month: "Invalid input format 😔 Specify the month by name or number, e.g. /month march or /month 3",
+    empty_query: "Specify a name or username to search for, e.g. /find John or /find @john",
+    invalid_date: "Invalid input format 😔 {error}. For example, /mybirthday 25-09 or /mybirthday March 5 1990",
+    group_only: "This command is available only in groups",
+    not_active: "Birthday notifications are not active in this chat, so you can't add your birthday 😞",
+    self_registration_forbidden: "The administrators have disabled adding own birthdays in this chat 🔒",
+    not_registered: "Your birthday is not in the list of this chat",
+    next_header: "Upcoming birthdays:",
+    today: "today! 🎉",
+    days_left: "in {days} {days_word}",
+    today_header: "Today we celebrate:",
+    memorial_header: "Today we remember:",
+    no_birthdays_today: "Nobody celebrates a birthday today",
+    month_empty: "{month}: no birthdays",
+    find_nothing: "Nothing found for «{query}»",
+    find_header: "Found for «{query}»:",
+    my_birthday_added: "Your birthday has been added! 🎉",
+    my_birthday_updated: "Your birthday has been updated! 🎉",
+    my_birthday_removed: "Your birthday has been removed from the list",
+    private_only: "This command is available only in private messages with me",
+    subscribe_usage: "Send /subscribe in the group whose reminders you want to receive in private messages",
+    subscribe_inactive: "Birthday notifications are not enabled in this group 😔 Administrators can enable them \
+        with /active",
+    subscribe_start_private: "I can't send you private messages 😔 Start a private chat with me by pressing «Start», \
+        then send /subscribe here again",
+    subscribed_in_group: "Done! I've sent you the confirmation of the subscription in private messages 🔔",
+    unsubscribe_list_header: "Your reminder subscriptions:",
+    unsubscribe_usage: "Send /unsubscribe number to unsubscribe from the reminders of a group",
+    no_subscriptions: "You have no reminder subscriptions",
+    subscription_not_found: "No group found with the given number 😔 Please try again",
+    subscribed: "Now I will send you birthday reminders from the group «{chat}» 🔔",
+    already_subscribed: "You are already subscribed to the reminders from the group «{chat}»",
+    unsubscribed: "You have unsubscribed from the reminders from the group «{chat}» 🔕",
+    subscription_header: "🔔 A reminder from the group «{chat}»:",
+    my_wishlist_header: "Your wishlist 🎁",
+    my_wishlist_empty: "The list is empty",
+    my_wishlist_usage: "Send /mywishlist text to add a wish (no more than {max}), \
+        /mywishlist remove number to remove it, or /mywishlist clear to clear the list. \
+        The wishes someone has already chosen are hidden from you, so the gift stays a surprise",
+    wishlist_item_added: "The wish has been added to the list 🎁",
+    wishlist_item_removed: "Wish {index} has been removed from the list",
+    wishlist_item_index_not_found: "No wish found with the given number 😔 Please try again",
+    wishlist_cleared: "The wishlist has been cleared",
+    wishlist_too_many: "The list can't contain more than {max} wishes",
+    wishlist_item_too_long: "A wish must not be longer than {max} characters",
+    wishlist_usage: "Specify the name or the username of the member, e.g. /wishlist John",
+    wishlist_not_found: "I couldn't find the member «{query}» with a wishlist 😔",
+    wishlist_header: "{name}'s wishlist 🎁",
+    wishlist_empty: "{name}'s wishlist is empty for now",
+    wishlist_hint: "Press a wish to choose it as your gift. The chosen wishes are hidden from the list",
+    wishlist_claim_button: "🎁 {index}. {item}",
+    wishlist_show_button: "🎁 {name}'s wishlist",
+    wishlist_claimed: "You have chosen the gift «{item}». Press again to cancel",
+    wishlist_unclaimed: "You have cancelled the gift «{item}»",
+    wishlist_already_claimed: "Someone else has already chosen this gift",
+    wishlist_own_claim: "You can't choose a gift from your own wishlist 🙂",
+    wishlist_item_not_found: "This wish is no longer in the list",
+
+    parse_missing_separator: "Separate the name from the date with a comma, e.g. «John Smith, March 5»",
+    parse_empty_name: "The name is missing",
+    parse_name_too_long: "The name must not be longer than {max} characters",
+    parse_invalid_name_char: "The name contains an invalid character «{char}»",
+    parse_missing_date: "The date is missing",
+    parse_unknown_date: "Couldn't recognize the date «{date}». Use, for example, «March 5», «5 марта», \
+        «05.03» or «05-03»",
+    parse_unknown_month: "Couldn't recognize the month «{month}»",
+    parse_invalid_month: "There is no month number {month}",
+    parse_invalid_day: "The date {day}.{month} doesn't exist",
+    parse_short_year: "Write the year «{year}» with four digits",
+    parse_invalid_year: "The year of birth {year} must be no earlier than {min} and no later than the current one",
+    parse_invalid_username: "«{username}» doesn't look like a Telegram username. A username may contain only \
+        Latin letters, digits and underscores",
+    parse_unknown_kind: "Unknown kind of event «{kind}». The available kinds: {kinds}",
+    parse_invalid_tag: "«{tag}» is not a valid tag. A tag starts with # and may contain only letters, digits, \
+        _ and -, no longer than {max} characters",
+
+    schedule_missing_separator: "Separate the date or the recurrence rule from the title with a comma, e.g. \
+        «every friday, Weekly sync»",
+    schedule_empty_title: "The title of the event is missing",
+    schedule_title_too_long: "The title of the event must not be longer than {max} characters",
+    schedule_past_date: "This date has already passed, or its reminders have already been sent",
+    schedule_unknown_rule: "Couldn't recognize «{rule}». Specify a date, e.g. 2026-12-20 or 20.12.2026, or a \
+        recurrence rule, e.g. «every friday», «every first friday», «every last friday» or «every 15»",
+
+    template_empty: "The template can't be empty",
+    template_too_long: "The template must not be longer than {max} characters",
+    template_unknown_placeholder: "Unknown placeholder {{placeholder}}. Available placeholders: {placeholders}",
+    template_unclosed_brace: "Every curly brace must have a pair, e.g. {name}",
+    template_no_person: "The template must contain {name} or {mention} so that it's clear whom to congratulate",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_replaces_placeholders() {
+        assert_eq!(
+            fill(
+                "{name} is {age}, {name}!",
+                &[("name", &"Anna"), ("age", &30)]
+            ),
+            "Anna is 30, Anna!"
+        );
+    }
+
+    #[test]
+    fn fill_keeps_unknown_placeholders_and_braces() {
+        assert_eq!(
+            fill("{{name}} {unknown} {", &[("name", &"Anna")]),
+            "{Anna} {unknown} {"
+        );
+    }
+
+    #[test]
+    fn fill_does_not_expand_placeholders_in_values() {
+        assert_eq!(
+            fill(
+                "{name}, {age}",
+                &[
+                    ("name", &"{age} {mention}"),
+                    ("age", &30),
+                    ("mention", &"link")
+                ]
+            ),
+            "{age} {mention}, 30"
+        );
+    }
+}
@@ -13,12 +13,27 @@ use tokio::sync::RwLock;
 
 mod args;
 mod birthday;
+mod calendar;
+mod card;
+mod collection;
+mod event;
+mod greeting;
 mod handles;
+mod i18n;
+mod nameday;
+mod parser;
+mod poll;
+mod schedule;
+mod settings;
 mod state;
 mod tasks;
 mod utils;
+mod wishlist;
 
 pub use birthday::{Birthday, Birthdays, BirthdaysMap, BirthdaysMapThreadSafe};
+pub use event::EventKind;
+pub use i18n::Language;
+pub use settings::{CardMode, DateOrder, DeliveryRule, Settings};
 pub use state::State;
 
 /// The user ID of the bot maintainer.
@@ -74,11 +89,15 @@ async fn main() -> std::io::Result<()> {
     };
     let birthdays_map_cloned = Arc::clone(&birthdays_map);
     let birthdays_map_cloned_for_backup = Arc::clone(&birthdays_map);
+    let birthdays_map_cloned_for_wd = Arc::clone(&birthdays_map);
+    let birthdays_map_cloned_for_md = Arc::clone(&birthdays_map);
 
     // Create a new bot instance
     let bot = Bot::new(token);
     let bot_for_br = bot.clone();
     let bot_for_hc = bot.clone();
+    let bot_for_wd = bot.clone();
+    let bot_for_md = bot.clone();
 
     // Create a task manager
     let task_manager = tasks::Manager::new(
@@ -100,6 +119,16 @@ async fn main() -> std::io::Result<()> {
             birthdays_map_cloned_for_backup.clone(),
             args.backup_path.clone(),
         )), // Daily backup
+        tokio::spawn(tasks::digest_task(
+            bot_for_wd,
+            birthdays_map_cloned_for_wd,
+            greeting::DigestPeriod::Weekly,
+        )), // Weekly digest
+        tokio::spawn(tasks::digest_task(
+            bot_for_md,
+            birthdays_map_cloned_for_md,
+            greeting::DigestPeriod::Monthly,
+        )), // Monthly digest
     );
 
     // Set configuration parameters
@@ -112,6 +141,11 @@ async fn main() -> std::io::Result<()> {
 
     log::info!("Bot maintainer user ID: {}", parameters.bot_maintainer);
 
+    // Register the localized lists of the bot commands
+    if let Err(e) = handles::set_my_commands(&bot).await {
+        log::error!("Failed to set the bot commands: {}", e);
+    }
+
     // Create and dispatch the bot using the configured dispatcher
     log::info!("Starting dispatching birthday reminder bot...");
     Dispatcher::builder(bot, build_handler())
@@ -133,57 +167,68 @@ async fn main() -> std::io::Result<()> {
 /// Builds the handler for processing bot updates.
 fn build_handler() -> Handler<'static, DependencyMap, Result<(), RequestError>, DpHandlerDescription>
 {
-    // Create the update filter for messages
-    Update::filter_message()
-        // Branch for handling simple commands
-        .branch(
-            dptree::entry()
-                .filter_command::<handles::Command>()
-                .endpoint(handles::base_commands_handler),
-        )
-        .branch(
-            dptree::filter_async(|msg: Message, cfg: ConfigParameters| async move {
-                msg.from()
-                    .map_or(false, |user| user.id == cfg.bot_maintainer)
-            })
-            .filter_command::<handles::MaintainerCommands>()
-            .endpoint(handles::maintainer_commands_handler),
-        )
-        // Branch for handling admin commands
+    dptree::entry()
+        // Create the update filter for messages
         .branch(
-            dptree::filter_async(|bot: Bot, msg: Message, cfg: ConfigParameters| async move {
-                if let Some(user) = msg.from() {
-                    user.id == cfg.bot_maintainer
-                        || ((msg.chat.is_group()
-                            || msg.chat.is_supergroup()
-                            || msg.chat.is_channel())
-                            && utils::is_admin(&bot, msg.chat.id, user.id)
-                                .await
-                                .unwrap_or_default())
-                        || msg.chat.is_chat()
-                } else {
-                    false
-                }
-            })
-            .filter_command::<handles::AdminCommands>()
-            .endpoint(handles::admin_commands_handler),
-        )
-        // Branch for handling common commands
-        .branch(
-            dptree::filter_async(|bot: Bot, msg: Message, cfg: ConfigParameters| async move {
-                if let Some(user) = msg.from() {
-                    user.id == cfg.bot_maintainer
-                        || ((msg.chat.is_group()
-                            || msg.chat.is_supergroup()
-                            || msg.chat.is_channel())
-                            && utils::is_admin(&bot, msg.chat.id, user.id)
-                                .await
-                                .unwrap_or_default())
-                        || msg.chat.is_chat()
-                } else {
-                    false
-                }
-            })
-            .endpoint(handles::common_commands_handler),
+            Update::filter_message()
+                // Branch for handling simple commands
+                .branch(
+                    dptree::entry()
+                        .filter_command::<handles::Command>()
+                        .endpoint(handles::base_commands_handler),
+                )
+                .branch(
+                    dptree::filter_async(|msg: Message, cfg: ConfigParameters| async move {
+                        msg.from()
+                            .map_or(false, |user| user.id == cfg.bot_maintainer)
+                    })
+                    .filter_command::<handles::MaintainerCommands>()
+                    .endpoint(handles::maintainer_commands_handler),
+                )
+                // Branch for handling admin commands
+                .branch(
+                    dptree::filter_async(
+                        |bot: Bot, msg: Message, cfg: ConfigParameters| async move {
+                            if let Some(user) = msg.from() {
+                                user.id == cfg.bot_maintainer
+                                    || ((msg.chat.is_group()
+                                        || msg.chat.is_supergroup()
+                                        || msg.chat.is_channel())
+                                        && utils::is_admin(&bot, msg.chat.id, user.id)
+                                            .await
+                                            .unwrap_or_default())
+                                    || msg.chat.is_chat()
+                            } else {
+                                false
+                            }
+                        },
+                    )
+                    .filter_command::<handles::AdminCommands>()
+                    .endpoint(handles::admin_commands_handler),
+                )
+                // Branch for handling common commands
+                .branch(
+                    dptree::filter_async(
+                        |bot: Bot, msg: Message, cfg: ConfigParameters| async move {
+                            if let Some(user) = msg.from() {
+                                user.id == cfg.bot_maintainer
+                                    || ((msg.chat.is_group()
+                                        || msg.chat.is_supergroup()
+                                        || msg.chat.is_channel())
+                                        && utils::is_admin(&bot, msg.chat.id, user.id)
+                                            .await
+                                            .unwrap_or_default())
+                                    || msg.chat.is_chat()
+                            } else {
+                                false
+                            }
+                        },
+                    )
+                    .endpoint(handles::common_commands_handler),
+                ),
         )
+        // Branch for handling inline buttons
+        .branch(Update::filter_callback_query().endpoint(handles::callback_query_handler))
+        // Branch for handling answers to the celebration polls
+        .branch(Update::filter_poll_answer().endpoint(handles::poll_answer_handler))
 }
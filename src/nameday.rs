@@ -0,0 +1,220 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::utils::normalize_text;
+use crate::{Birthday, Birthdays, EventKind};
+
+/// The Orthodox name-day calendar: the church form of the name and the days and months of the
+/// main feasts of the saints bearing it, in the civil (Gregorian) calendar.
+const CALENDAR: &[(&str, &[(u32, u32)])] = &[
+    ("александр", &[(12, 9), (6, 12)]),
+    ("александра", &[(6, 5)]),
+    ("алексей", &[(25, 2), (30, 3), (2, 6)]),
+    ("алла", &[(8, 4)]),
+    ("анастасия", &[(4, 1), (11, 11)]),
+    ("андрей", &[(17, 7), (13, 12)]),
+    ("анна", &[(3, 2), (7, 8), (22, 12)]),
+    ("антоний", &[(30, 1), (23, 7)]),
+    ("аполлинария", &[(18, 1)]),
+    ("борис", &[(2, 5), (6, 8)]),
+    ("вадим", &[(22, 4)]),
+    ("варвара", &[(17, 12)]),
+    ("василий", &[(14, 1), (12, 2)]),
+    ("вера", &[(30, 9)]),
+    ("виталий", &[(11, 5)]),
+    ("владимир", &[(28, 7)]),
+    ("всеволод", &[(24, 2)]),
+    ("вячеслав", &[(11, 10)]),
+    ("галина", &[(23, 3)]),
+    ("георгий", &[(6, 5), (9, 12)]),
+    ("глеб", &[(2, 5), (6, 8)]),
+    ("григорий", &[(7, 2)]),
+    ("даниил", &[(17, 3), (30, 12)]),
+    ("дарья", &[(1, 4)]),
+    ("денис", &[(16, 10)]),
+    ("дмитрий", &[(1, 6), (8, 11)]),
+    ("евгения", &[(6, 1)]),
+    ("евдокия", &[(14, 3)]),
+    ("евгений", &[(26, 12)]),
+    ("екатерина", &[(7, 12)]),
+    ("елена", &[(3, 6)]),
+    ("елизавета", &[(18, 7), (18, 9)]),
+    ("иван", &[(21, 5), (7, 7), (11, 9), (9, 10)]),
+    ("игорь", &[(18, 6), (2, 10)]),
+    ("илья", &[(2, 8)]),
+    ("ирина", &[(18, 5)]),
+    ("кирилл", &[(31, 1), (24, 5)]),
+    ("константин", &[(3, 6)]),
+    ("ксения", &[(6, 2)]),
+    ("лариса", &[(8, 4)]),
+    ("лидия", &[(5, 4)]),
+    ("любовь", &[(30, 9)]),
+    ("людмила", &[(29, 9)]),
+    ("лука", &[(31, 10)]),
+    ("макар", &[(1, 2)]),
+    ("максим", &[(3, 2), (26, 8)]),
+    ("марина", &[(30, 7)]),
+    ("мария", &[(14, 4), (4, 8)]),
+    ("марк", &[(8, 5)]),
+    ("матвей", &[(29, 11)]),
+    ("михаил", &[(21, 11)]),
+    ("надежда", &[(30, 9)]),
+    ("наталья", &[(8, 9)]),
+    ("никита", &[(28, 9)]),
+    ("николай", &[(22, 5), (19, 12)]),
+    ("нина", &[(27, 1)]),
+    ("олег", &[(3, 10)]),
+    ("ольга", &[(24, 7)]),
+    ("павел", &[(12, 7)]),
+    ("петр", &[(12, 7)]),
+    ("ростислав", &[(27, 3)]),
+    ("светлана", &[(26, 2)]),
+    ("серафим", &[(15, 1)]),
+    ("сергей", &[(18, 7), (8, 10)]),
+    ("симеон", &[(16, 2)]),
+    ("софья", &[(30, 9)]),
+    ("стефан", &[(9, 1)]),
+    ("тамара", &[(14, 5)]),
+    ("татьяна", &[(25, 1)]),
+    ("тимофей", &[(4, 2)]),
+    ("тихон", &[(29, 6)]),
+    ("фома", &[(19, 10)]),
+    ("филипп", &[(27, 11)]),
+    ("юлия", &[(29, 7)]),
+    ("яков", &[(5, 11)]),
+];
+
+/// The diminutive and civil forms of the names mapped to the church forms used in the calendar.
+const DIMINUTIVES: &[(&str, &str)] = &[
+    ("саша", "александр"),
+    ("шура", "александр"),
+    ("алеша", "алексей"),
+    ("леша", "алексей"),
+    ("настя", "анастасия"),
+    ("андрюша", "андрей"),
+    ("аня", "анна"),
+    ("антон", "антоний"),
+    ("полина", "аполлинария"),
+    ("боря", "борис"),
+    ("варя", "варвара"),
+    ("вася", "василий"),
+    ("вова", "владимир"),
+    ("володя", "владимир"),
+    ("слава", "вячеслав"),
+    ("галя", "галина"),
+    ("егор", "георгий"),
+    ("юрий", "георгий"),
+    ("юра", "георгий"),
+    ("гоша", "георгий"),
+    ("жора", "георгий"),
+    ("гриша", "григорий"),
+    ("данила", "даниил"),
+    ("даня", "даниил"),
+    ("даша", "дарья"),
+    ("дима", "дмитрий"),
+    ("митя", "дмитрий"),
+    ("женя", "евгений"),
+    ("дуня", "евдокия"),
+    ("катя", "екатерина"),
+    ("лена", "елена"),
+    ("лиза", "елизавета"),
+    ("ваня", "иван"),
+    ("илюша", "илья"),
+    ("ира", "ирина"),
+    ("кирюша", "кирилл"),
+    ("костя", "константин"),
+    ("оксана", "ксения"),
+    ("ксюша", "ксения"),
+    ("люба", "любовь"),
+    ("люда", "людмила"),
+    ("макс", "максим"),
+    ("маргарита", "марина"),
+    ("маша", "мария"),
+    ("марья", "мария"),
+    ("миша", "михаил"),
+    ("надя", "надежда"),
+    ("наталия", "наталья"),
+    ("наташа", "наталья"),
+    ("коля", "николай"),
+    ("оля", "ольга"),
+    ("паша", "павел"),
+    ("петя", "петр"),
+    ("света", "светлана"),
+    ("семен", "симеон"),
+    ("сеня", "симеон"),
+    ("сережа", "сергей"),
+    ("софия", "софья"),
+    ("соня", "софья"),
+    ("степан", "стефан"),
+    ("тома", "тамара"),
+    ("таня", "татьяна"),
+    ("тима", "тимофей"),
+    ("юля", "юлия"),
+];
+
+/// Returns the name days of the person according to the calendar.
+/// The first name is looked up in the calendar directly or through its diminutive form, e.g.
+/// "Ваня Петров" celebrates on the days of Иван.
+///
+/// # Arguments
+///
+/// * `name` - The full name of the person.
+///
+/// # Returns
+///
+/// The days and months of the name days, empty if the name is not in the calendar.
+fn name_days(name: &str) -> &'static [(u32, u32)] {
+    let first = match name.split_whitespace().next() {
+        Some(first) => normalize_text(first),
+        None => return &[],
+    };
+    let first = DIMINUTIVES
+        .iter()
+        .find(|(diminutive, _)| *diminutive == first)
+        .map_or(first.as_str(), |(_, full)| *full);
+    CALENDAR
+        .iter()
+        .find(|(name, _)| *name == first)
+        .map_or(&[], |(_, dates)| *dates)
+}
+
+/// Returns whether the person with the given name celebrates the name day on the date.
+///
+/// # Arguments
+///
+/// * `name` - The full name of the person.
+/// * `date` - The date to check.
+///
+/// # Returns
+///
+/// `true` if the first name of the person is among the saints of the day, otherwise `false`.
+pub fn is_name_day(name: &str, date: NaiveDate) -> bool {
+    name_days(name)
+        .iter()
+        .any(|(day, month)| *day == date.day() && *month == date.month())
+}
+
+/// Returns the people of the chat celebrating the name day on the date according to the calendar.
+/// The people whose name days are already added to the list as events are skipped, as they are
+/// reminded about with the regular reminders.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays of the chat.
+/// * `date` - The date to check.
+///
+/// # Returns
+///
+/// A vector of the birthdays of the people celebrating the name day.
+pub fn celebrants(birthdays: &Birthdays, date: NaiveDate) -> Vec<&Birthday> {
+    let has_name_day = |person: &Birthday| {
+        birthdays
+            .iter()
+            .any(|event| event.kind == EventKind::NameDay && event.name == person.name)
+    };
+    birthdays
+        .iter()
+        .filter(|birthday| birthday.kind.is_birthday())
+        .filter(|birthday| is_name_day(&birthday.name, date))
+        .filter(|birthday| !has_name_day(birthday))
+        .collect()
+}
@@ -0,0 +1,538 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use regex::Regex;
+
+use crate::i18n::fill;
+use crate::{Birthday, DateOrder, EventKind, Language};
+
+/// The maximum length of the name of the person in characters.
+const NAME_MAX_LEN: usize = 100;
+
+/// The earliest year of birth accepted by the parser.
+const MIN_YEAR: i32 = 1900;
+
+/// The maximum length of a tag in characters.
+const TAG_MAX_LEN: usize = 32;
+
+/// Represents an error that can occur when parsing a birthday.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The name and the date are not separated by a comma.
+    MissingSeparator,
+    /// The name is empty.
+    EmptyName,
+    /// The name is longer than allowed.
+    NameTooLong,
+    /// The name contains a character that is not allowed.
+    InvalidNameChar(char),
+    /// The date is missing.
+    MissingDate,
+    /// The date could not be recognized.
+    UnknownDate(String),
+    /// The month name could not be recognized.
+    UnknownMonth(String),
+    /// The month number is out of range.
+    InvalidMonth(u32),
+    /// The day does not exist in the month.
+    InvalidDay(u32, u32),
+    /// The year is not written with four digits.
+    ShortYear(String),
+    /// The year is out of the accepted range.
+    InvalidYear(i32),
+    /// The username is not a valid Telegram username.
+    InvalidUsername(String),
+    /// The kind of the event could not be recognized.
+    UnknownKind(String),
+    /// The tag is empty, too long or contains a character that is not allowed.
+    InvalidTag(String),
+}
+
+impl ParseError {
+    /// Returns the explanation of the error in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the explanation.
+    ///
+    /// # Returns
+    ///
+    /// A `String` explaining what is wrong with the input.
+    pub fn describe(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        match self {
+            ParseError::MissingSeparator => tr.parse_missing_separator.to_string(),
+            ParseError::EmptyName => tr.parse_empty_name.to_string(),
+            ParseError::NameTooLong => fill(tr.parse_name_too_long, &[("max", &NAME_MAX_LEN)]),
+            ParseError::InvalidNameChar(c) => fill(tr.parse_invalid_name_char, &[("char", c)]),
+            ParseError::MissingDate => tr.parse_missing_date.to_string(),
+            ParseError::UnknownDate(date) => fill(tr.parse_unknown_date, &[("date", date)]),
+            ParseError::UnknownMonth(month) => fill(tr.parse_unknown_month, &[("month", month)]),
+            ParseError::InvalidMonth(month) => fill(tr.parse_invalid_month, &[("month", month)]),
+            ParseError::InvalidDay(day, month) => fill(
+                tr.parse_invalid_day,
+                &[
+                    ("day", &format!("{:02}", day)),
+                    ("month", &format!("{:02}", month)),
+                ],
+            ),
+            ParseError::ShortYear(year) => fill(tr.parse_short_year, &[("year", year)]),
+            ParseError::InvalidYear(year) => {
+                fill(tr.parse_invalid_year, &[("year", year), ("min", &MIN_YEAR)])
+            }
+            ParseError::InvalidUsername(username) => {
+                fill(tr.parse_invalid_username, &[("username", username)])
+            }
+            ParseError::UnknownKind(kind) => fill(
+                tr.parse_unknown_kind,
+                &[("kind", kind), ("kinds", &EventKind::names())],
+            ),
+            ParseError::InvalidTag(tag) => {
+                fill(tr.parse_invalid_tag, &[("tag", tag), ("max", &TAG_MAX_LEN)])
+            }
+        }
+    }
+}
+
+/// Parses the input string to create a `Birthday` struct.
+/// The input string should be in the format "name, date, @username" or "name, date". The name may
+/// consist of several words and contain hyphens. The date may be written as "5 марта", "March 5",
+/// "05.03", "5/3" or "05-03" with an optional year, and the order of the numeric day and month is
+/// defined by the chat settings. Other events are prefixed with the kind, e.g.
+/// "work: name, date", and the tags are added as hashtags, e.g. "name, date #backend".
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+/// * `order` - The order of the day and month in numeric dates.
+///
+/// # Returns
+///
+/// A `Birthday` struct if the input is valid, otherwise a `ParseError` explaining the problem.
+pub fn parse_birthday(input: &str, order: DateOrder) -> Result<Birthday, ParseError> {
+    let (kind, input) = parse_kind(input)?;
+    let (tags, input) = parse_tags(input)?;
+    let (name, rest) = input
+        .trim()
+        .split_once(',')
+        .ok_or(ParseError::MissingSeparator)?;
+    let name = parse_name(name)?;
+
+    let mut parts = rest.split(',').map(str::trim).collect::<Vec<_>>();
+
+    // The username is the last part if it starts with "@", or if it is a single word following
+    // the date.
+    let username = match parts.last() {
+        Some(last) if last.starts_with('@') => parts.pop(),
+        Some(last)
+            if parts.len() > 1
+                && !last.is_empty()
+                && !last.chars().all(|c| c.is_ascii_digit())
+                && last.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+        {
+            parts.pop()
+        }
+        _ => None,
+    }
+    .map(parse_username)
+    .transpose()?
+    .unwrap_or_default();
+
+    let (date, year) = parse_date(&parts.join(" "), order)?;
+
+    Ok(Birthday {
+        name,
+        date,
+        username,
+        year,
+        kind,
+        tags,
+        ..Default::default()
+    })
+}
+
+/// Parses the hashtags in the input, e.g. "Иван, 5 марта #backend #family".
+/// The hashtags may be placed anywhere in the input, as "#" is not allowed in names and dates.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+///
+/// # Returns
+///
+/// A tuple of the lowercase tags without "#" and the rest of the input, otherwise a `ParseError`
+/// if a tag is invalid.
+pub fn parse_tags(input: &str) -> Result<(Vec<String>, String), ParseError> {
+    let mut tags = Vec::new();
+    let mut rest = Vec::new();
+
+    for word in input.split_whitespace() {
+        let tag = match word.strip_prefix('#') {
+            Some(tag) => tag,
+            None => {
+                rest.push(word);
+                continue;
+            }
+        };
+        let tag = crate::utils::normalize_text(tag.trim_end_matches(','));
+        if tag.is_empty()
+            || tag.chars().count() > TAG_MAX_LEN
+            || !tag
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))
+        {
+            return Err(ParseError::InvalidTag(word.to_string()));
+        }
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    let rest = rest.join(" ");
+    Ok((
+        tags,
+        rest.trim_end_matches(|c: char| c == ',' || c.is_whitespace())
+            .to_string(),
+    ))
+}
+
+/// Parses the optional kind of the event at the start of the input, e.g. "work: 05.03.2015".
+/// The kind is separated with a colon, which is not allowed in names and dates.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+///
+/// # Returns
+///
+/// A tuple of the kind of the event, or the birthday if the kind is omitted, and the rest of the
+/// input, otherwise a `ParseError` if the kind is not recognized.
+pub fn parse_kind(input: &str) -> Result<(EventKind, &str), ParseError> {
+    match input.split_once(':') {
+        Some((kind, rest)) => EventKind::parse(kind)
+            .map(|kind| (kind, rest))
+            .ok_or_else(|| ParseError::UnknownKind(kind.trim().to_string())),
+        None => Ok((EventKind::Birthday, input)),
+    }
+}
+
+/// Parses the input string to create a birthday date.
+/// The date may be written as "5 марта", "March 5", "05.03", "5/3" or "05-03" with an optional
+/// year, e.g. "5 марта 1990" or "05.03.1990". Dates in the "YYYY-MM-DD" format are accepted as well.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+/// * `order` - The order of the day and month in numeric dates.
+///
+/// # Returns
+///
+/// A tuple of the date in the format "DD-MM" and the optional year if the input is valid,
+/// otherwise a `ParseError` explaining the problem.
+pub fn parse_date(input: &str, order: DateOrder) -> Result<(String, Option<i32>), ParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseError::MissingDate);
+    }
+
+    let tokens = input
+        .split(|c: char| c.is_whitespace() || matches!(c, '.' | '/' | '-'))
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+
+    let (words, numbers): (Vec<&str>, Vec<&str>) = tokens
+        .into_iter()
+        .partition(|token| !token.chars().all(|c| c.is_ascii_digit()));
+
+    let (day, month, year) = match (words.as_slice(), numbers.as_slice()) {
+        // A date with the month name, e.g. "5 марта 1990" or "March 5".
+        ([month], [day, rest @ ..]) if rest.len() <= 1 => {
+            let month = crate::utils::parse_month(month)
+                .ok_or_else(|| ParseError::UnknownMonth(month.to_string()))?;
+            (parse_number(day, input)?, month, rest.first())
+        }
+        ([], [first, month, day]) if first.len() == 4 => (
+            parse_number(day, input)?,
+            parse_number(month, input)?,
+            Some(first),
+        ),
+        ([], [first, second, rest @ ..]) if rest.len() <= 1 => {
+            let (day, month) = match order {
+                DateOrder::DayMonth => (first, second),
+                DateOrder::MonthDay => (second, first),
+            };
+            (
+                parse_number(day, input)?,
+                parse_number(month, input)?,
+                rest.first(),
+            )
+        }
+        _ => return Err(ParseError::UnknownDate(input.to_string())),
+    };
+
+    if !(1..=12).contains(&month) {
+        return Err(ParseError::InvalidMonth(month));
+    }
+
+    let year = match year {
+        Some(year) if year.len() != 4 => return Err(ParseError::ShortYear(year.to_string())),
+        Some(year) => {
+            let year = year
+                .parse::<i32>()
+                .map_err(|_| ParseError::UnknownDate(input.to_string()))?;
+            if year < MIN_YEAR || year > Utc::now().year() {
+                return Err(ParseError::InvalidYear(year));
+            }
+            Some(year)
+        }
+        None => None,
+    };
+
+    // 2000 is a leap year, so the 29th of February is accepted when the year is omitted.
+    let date = NaiveDate::from_ymd_opt(year.unwrap_or(2000), month, day)
+        .ok_or(ParseError::InvalidDay(day, month))?;
+    if date > Utc::now().date_naive() && year.is_some() {
+        return Err(ParseError::InvalidYear(date.year()));
+    }
+
+    Ok((format!("{:02}-{:02}", day, month), year))
+}
+
+/// Parses the name of the person.
+/// The name may contain letters, digits, spaces, hyphens, apostrophes and dots.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+///
+/// # Returns
+///
+/// The trimmed name with collapsed whitespaces if it is valid, otherwise a `ParseError`.
+fn parse_name(input: &str) -> Result<String, ParseError> {
+    let name = input.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if name.is_empty() {
+        return Err(ParseError::EmptyName);
+    }
+    if name.chars().count() > NAME_MAX_LEN {
+        return Err(ParseError::NameTooLong);
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_alphanumeric() || matches!(c, ' ' | '-' | '\'' | '’' | '.')))
+    {
+        return Err(ParseError::InvalidNameChar(c));
+    }
+
+    Ok(name)
+}
+
+/// Parses the Telegram username of the person.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse, with or without the leading "@".
+///
+/// # Returns
+///
+/// The username with the leading "@" if it is valid, otherwise a `ParseError`.
+fn parse_username(input: &str) -> Result<String, ParseError> {
+    let re = Regex::new(r"^@?(?P<username>[A-Za-z0-9_]+)$").unwrap();
+    re.captures(input)
+        .and_then(|caps| caps.name("username"))
+        .map(|username| format!("@{}", username.as_str()))
+        .ok_or_else(|| ParseError::InvalidUsername(input.to_string()))
+}
+
+/// Parses the day or month number.
+///
+/// # Arguments
+///
+/// * `input` - The input string to parse.
+/// * `date` - The whole date, used for the error message.
+///
+/// # Returns
+///
+/// The number if the input has at most two digits, otherwise a `ParseError`.
+fn parse_number(input: &str, date: &str) -> Result<u32, ParseError> {
+    match input.parse::<u32>() {
+        Ok(number) if input.len() <= 2 => Ok(number),
+        _ => Err(ParseError::UnknownDate(date.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(input: &str, order: DateOrder) -> Result<(String, Option<i32>), ParseError> {
+        parse_date(input, order)
+    }
+
+    #[test]
+    fn parses_dates_with_month_names() {
+        let order = DateOrder::DayMonth;
+        assert_eq!(
+            date("5 марта 1990", order),
+            Ok(("05-03".to_string(), Some(1990)))
+        );
+        assert_eq!(date("5 марта", order), Ok(("05-03".to_string(), None)));
+        assert_eq!(date("March 5", order), Ok(("05-03".to_string(), None)));
+        assert_eq!(
+            date("March 5 1990", order),
+            Ok(("05-03".to_string(), Some(1990)))
+        );
+        assert_eq!(date("17 Dec.", order), Ok(("17-12".to_string(), None)));
+        // The month name wins over the order of the chat.
+        assert_eq!(
+            date("5 марта", DateOrder::MonthDay),
+            Ok(("05-03".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn parses_numeric_dates_in_the_order_of_the_chat() {
+        for input in ["05.03", "5/3", "05-03", "5.3"] {
+            assert_eq!(
+                date(input, DateOrder::DayMonth),
+                Ok(("05-03".to_string(), None)),
+                "{}",
+                input
+            );
+            assert_eq!(
+                date(input, DateOrder::MonthDay),
+                Ok(("03-05".to_string(), None)),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            date("05.03.1990", DateOrder::DayMonth),
+            Ok(("05-03".to_string(), Some(1990)))
+        );
+        assert_eq!(
+            date("03/05/1990", DateOrder::MonthDay),
+            Ok(("05-03".to_string(), Some(1990)))
+        );
+        // The ISO format doesn't depend on the order.
+        for order in [DateOrder::DayMonth, DateOrder::MonthDay] {
+            assert_eq!(
+                date("1990-03-05", order),
+                Ok(("05-03".to_string(), Some(1990)))
+            );
+        }
+    }
+
+    #[test]
+    fn parses_the_29th_of_february() {
+        let order = DateOrder::DayMonth;
+        assert_eq!(date("29.02", order), Ok(("29-02".to_string(), None)));
+        assert_eq!(date("29 февраля", order), Ok(("29-02".to_string(), None)));
+        assert_eq!(
+            date("29.02.2000", order),
+            Ok(("29-02".to_string(), Some(2000)))
+        );
+        assert_eq!(
+            date("29.02.2001", order),
+            Err(ParseError::InvalidDay(29, 2))
+        );
+        assert_eq!(
+            date("1900-02-29", order),
+            Err(ParseError::InvalidDay(29, 2))
+        );
+        assert_eq!(
+            date("30 февраля", order),
+            Err(ParseError::InvalidDay(30, 2))
+        );
+    }
+
+    #[test]
+    fn parses_names_with_several_words_and_hyphens() {
+        let birthday =
+            parse_birthday("Анна-Мария  Иванова-Петрова, 5 марта", DateOrder::DayMonth).unwrap();
+        assert_eq!(birthday.name, "Анна-Мария Иванова-Петрова");
+        assert_eq!(birthday.date, "05-03");
+        assert_eq!(birthday.username, "");
+
+        let birthday = parse_birthday(
+            "John Ronald Reuel Tolkien, 3 January 1952",
+            DateOrder::DayMonth,
+        )
+        .unwrap();
+        assert_eq!(birthday.name, "John Ronald Reuel Tolkien");
+        assert_eq!(birthday.year, Some(1952));
+
+        let birthday = parse_birthday("Jean-Luc O'Neil Jr., 05.03", DateOrder::DayMonth).unwrap();
+        assert_eq!(birthday.name, "Jean-Luc O'Neil Jr.");
+    }
+
+    #[test]
+    fn detects_usernames() {
+        let order = DateOrder::DayMonth;
+        let birthday = parse_birthday("Иван Петров, 5 марта, @ivan_99", order).unwrap();
+        assert_eq!(birthday.username, "@ivan_99");
+        assert_eq!(birthday.date, "05-03");
+
+        // A single word after the date is a username even without "@".
+        let birthday = parse_birthday("Иван Петров, 05.03.1990, ivan_99", order).unwrap();
+        assert_eq!(birthday.username, "@ivan_99");
+        assert_eq!(birthday.year, Some(1990));
+
+        // A year after the date is not a username.
+        let birthday = parse_birthday("Иван Петров, 05.03, 1990", order).unwrap();
+        assert_eq!(birthday.username, "");
+        assert_eq!(birthday.year, Some(1990));
+    }
+
+    #[test]
+    fn parses_kinds_and_tags() {
+        let birthday = parse_birthday(
+            "work: Иван, 5 марта 2015 #Backend #family",
+            DateOrder::DayMonth,
+        )
+        .unwrap();
+        assert_eq!(birthday.kind, EventKind::WorkAnniversary);
+        assert_eq!(birthday.tags, ["backend", "family"]);
+        assert_eq!(birthday.year, Some(2015));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let order = DateOrder::DayMonth;
+        let long_name = format!("{}, 5 марта", "а".repeat(NAME_MAX_LEN + 1));
+        let cases = [
+            ("Иван 5 марта", ParseError::MissingSeparator),
+            (" , 5 марта", ParseError::EmptyName),
+            (long_name.as_str(), ParseError::NameTooLong),
+            ("Иван!, 5 марта", ParseError::InvalidNameChar('!')),
+            ("Иван, , @ivan", ParseError::MissingDate),
+            ("Иван, скоро", ParseError::UnknownDate("скоро".to_string())),
+            (
+                "Иван, 123.03",
+                ParseError::UnknownDate("123.03".to_string()),
+            ),
+            (
+                "Иван, 5 мартобря",
+                ParseError::UnknownMonth("мартобря".to_string()),
+            ),
+            ("Иван, 05.13", ParseError::InvalidMonth(13)),
+            ("Иван, 31.04", ParseError::InvalidDay(31, 4)),
+            ("Иван, 05.03.90", ParseError::ShortYear("90".to_string())),
+            ("Иван, 05.03.1850", ParseError::InvalidYear(1850)),
+            ("Иван, 05.03.2999", ParseError::InvalidYear(2999)),
+            (
+                "Иван, 5 марта, @иван",
+                ParseError::InvalidUsername("@иван".to_string()),
+            ),
+            (
+                "party: Иван, 5 марта",
+                ParseError::UnknownKind("party".to_string()),
+            ),
+            (
+                "Иван, 5 марта #back!end",
+                ParseError::InvalidTag("#back!end".to_string()),
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_birthday(input, order), Err(expected), "{}", input);
+        }
+    }
+}
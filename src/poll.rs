@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use teloxide::types::{MessageId, UserId};
+
+use crate::i18n::fill;
+use crate::{Birthday, Language};
+
+/// The maximum number of celebration polls in a chat.
+pub const MAX_POLLS: usize = 10;
+
+/// The minimum number of options of a poll in Telegram.
+pub const MIN_OPTIONS: usize = 2;
+
+/// The maximum number of options of a poll in Telegram.
+pub const MAX_OPTIONS: usize = 10;
+
+/// The maximum length of the question of a poll in Telegram.
+pub const QUESTION_MAX_LEN: usize = 300;
+
+/// The maximum length of an option of a poll in Telegram.
+pub const OPTION_MAX_LEN: usize = 100;
+
+/// Represents an error that can occur when parsing a poll typed by an administrator.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PollError {
+    /// The poll has fewer options than Telegram allows.
+    TooFewOptions,
+    /// The poll has more options than Telegram allows.
+    TooManyOptions,
+    /// The question is longer than Telegram allows.
+    QuestionTooLong,
+    /// The option is longer than Telegram allows.
+    OptionTooLong(String),
+}
+
+impl PollError {
+    /// Returns the description of the error in the given language.
+    pub fn describe(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        match self {
+            PollError::TooFewOptions => fill(tr.poll_too_few_options, &[("min", &MIN_OPTIONS)]),
+            PollError::TooManyOptions => fill(tr.poll_too_many_options, &[("max", &MAX_OPTIONS)]),
+            PollError::QuestionTooLong => {
+                fill(tr.poll_question_too_long, &[("max", &QUESTION_MAX_LEN)])
+            }
+            PollError::OptionTooLong(option) => fill(
+                tr.poll_option_too_long,
+                &[("option", option), ("max", &OPTION_MAX_LEN)],
+            ),
+        }
+    }
+}
+
+/// Parses the question and the options of a poll.
+/// The question and the options are separated by semicolons or line breaks, e.g.
+/// "What shall we give?; A book; A certificate".
+///
+/// # Arguments
+///
+/// * `input` - The text of the poll.
+///
+/// # Returns
+///
+/// A tuple of the question and the options if the poll is valid, otherwise a `PollError`.
+pub fn parse(input: &str) -> Result<(String, Vec<String>), PollError> {
+    let mut parts = input
+        .split([';', '\n'])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(str::to_string);
+    let question = parts.next().unwrap_or_default();
+    let options = parts.collect::<Vec<_>>();
+
+    if options.len() < MIN_OPTIONS {
+        return Err(PollError::TooFewOptions);
+    }
+    if options.len() > MAX_OPTIONS {
+        return Err(PollError::TooManyOptions);
+    }
+    if question.chars().count() > QUESTION_MAX_LEN {
+        return Err(PollError::QuestionTooLong);
+    }
+    if let Some(option) = options
+        .iter()
+        .find(|option| option.chars().count() > OPTION_MAX_LEN)
+    {
+        return Err(PollError::OptionTooLong(option.clone()));
+    }
+
+    Ok((question, options))
+}
+
+/// Represents a poll about the celebration of an upcoming birthday, e.g. about the gift or the
+/// restaurant. The poll is posted with the advance reminder and summarized the day before the
+/// birthday.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CelebrationPoll {
+    /// The ID of the poll, unique within the chat.
+    pub id: u32,
+    /// The name of the person whose birthday is celebrated.
+    pub name: String,
+    /// The date of the birthday.
+    pub date: String,
+    /// The Telegram user ID of the person if the birthday is linked to their account. It keeps the
+    /// poll attached to the birthday when the name or the date is changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    /// The question of the poll.
+    pub question: String,
+    /// The options of the poll.
+    pub options: Vec<String>,
+    /// The Telegram ID of the poll once it is posted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll_id: Option<String>,
+    /// The ID of the message with the poll once it is posted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    /// The options chosen by the members.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub votes: HashMap<UserId, Vec<usize>>,
+}
+
+impl CelebrationPoll {
+    /// Returns whether the poll is about the given birthday.
+    /// The linked birthdays are recognized by the Telegram user ID, the others by the name and
+    /// the date.
+    ///
+    /// # Arguments
+    ///
+    /// * `birthday` - The birthday to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the poll is attached to the birthday, otherwise `false`.
+    pub fn is_about(&self, birthday: &Birthday) -> bool {
+        match self.user_id {
+            Some(user_id) => birthday.kind.is_birthday() && birthday.user_id == Some(user_id),
+            None => birthday.name == self.name && birthday.date == self.date,
+        }
+    }
+
+    /// Records the answer of the member, or forgets it if the member retracted the vote.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The Telegram user ID of the member.
+    /// * `option_ids` - The 0-based indices of the chosen options, empty if the vote is retracted.
+    pub fn vote(&mut self, user_id: UserId, option_ids: &[i32]) {
+        let chosen = option_ids
+            .iter()
+            .filter_map(|id| usize::try_from(*id).ok())
+            .filter(|id| *id < self.options.len())
+            .collect::<Vec<_>>();
+        if chosen.is_empty() {
+            self.votes.remove(&user_id);
+        } else {
+            self.votes.insert(user_id, chosen);
+        }
+    }
+
+    /// Returns the summary of the results of the poll.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the chat.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the options sorted by the number of votes and the winning option.
+    pub fn summary(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        let mut results = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                let count = self
+                    .votes
+                    .values()
+                    .filter(|chosen| chosen.contains(&idx))
+                    .count();
+                (option, count)
+            })
+            .collect::<Vec<_>>();
+        // The sort is stable, so the options with the same number of votes keep their order.
+        results.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        let lines = results
+            .iter()
+            .map(|(option, count)| {
+                let votes = lang.plural(*count as i64, &tr.votes);
+                format!("{} — {} {}\n", option, count, votes)
+            })
+            .collect::<String>();
+        let top = results.first().map_or(0, |(_, count)| *count);
+        let leaders = results
+            .iter()
+            .filter(|(_, count)| *count == top)
+            .map(|(option, _)| option.as_str())
+            .collect::<Vec<_>>();
+        let verdict = match leaders.as_slice() {
+            _ if top == 0 => tr.poll_no_votes.to_string(),
+            [winner] => fill(tr.poll_winner, &[("option", winner)]),
+            _ => fill(tr.poll_tie, &[("options", &leaders.join(", "))]),
+        };
+
+        fill(
+            tr.poll_summary,
+            &[
+                ("question", &self.question),
+                ("name", &self.name),
+                ("results", &lines),
+                ("verdict", &verdict),
+            ],
+        )
+    }
+}
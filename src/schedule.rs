@@ -0,0 +1,477 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::fill;
+use crate::Language;
+
+/// The maximum number of scheduled events per chat.
+pub const MAX_EVENTS: usize = 30;
+
+/// The maximum length of the title of the scheduled event in characters.
+const TITLE_MAX_LEN: usize = 200;
+
+/// The number of days to look ahead for the next occurrence of the event.
+const LOOKAHEAD_DAYS: i64 = 400;
+
+/// Represents the rule which defines the days the scheduled event occurs on.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    /// The event occurs once on the date.
+    Once { date: NaiveDate },
+    /// The event occurs every week on the weekday.
+    Weekly { weekday: Weekday },
+    /// The event occurs every month on the day, or on the last day of shorter months.
+    MonthlyDay { day: u32 },
+    /// The event occurs every month on the weekday of the week, e.g. on the first Friday.
+    MonthlyWeekday { week: u32, weekday: Weekday },
+    /// The event occurs every month on the last weekday, e.g. on the last Friday.
+    LastWeekday { weekday: Weekday },
+}
+
+impl Recurrence {
+    /// Returns whether the event occurs on the given date.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the event occurs on the date, otherwise `false`.
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        match *self {
+            Recurrence::Once { date: once } => date == once,
+            Recurrence::Weekly { weekday } => date.weekday() == weekday,
+            Recurrence::MonthlyDay { day } => {
+                let is_last_day = (date + Duration::days(1)).month() != date.month();
+                date.day() == day || (is_last_day && date.day() < day)
+            }
+            Recurrence::MonthlyWeekday { week, weekday } => {
+                date.weekday() == weekday && (date.day() - 1) / 7 + 1 == week
+            }
+            Recurrence::LastWeekday { weekday } => {
+                date.weekday() == weekday && (date + Duration::days(7)).month() != date.month()
+            }
+        }
+    }
+
+    /// Returns the date of the next occurrence of the event, including the given date itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The date to start searching from.
+    ///
+    /// # Returns
+    ///
+    /// Some date of the next occurrence, or None if the one-off event has already passed.
+    pub fn next_date(&self, today: NaiveDate) -> Option<NaiveDate> {
+        (0..LOOKAHEAD_DAYS)
+            .map(|days| today + Duration::days(days))
+            .find(|date| self.occurs_on(*date))
+    }
+
+    /// Returns whether the event will never occur after the given date.
+    pub fn is_finished(&self, today: NaiveDate) -> bool {
+        matches!(*self, Recurrence::Once { date } if date <= today)
+    }
+
+    /// Parses the recurrence rule typed by an administrator.
+    /// One-off events are written as a date, e.g. "2026-12-20" or "20.12.2026". Recurring events
+    /// start with "every" or "каждый", e.g. "every friday", "every first friday",
+    /// "every last friday", "every 15" or "каждую первую пятницу".
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input string to parse.
+    /// * `first_date` - The first date the reminders are still to be sent on, one-off events
+    ///   can't be scheduled before it.
+    ///
+    /// # Returns
+    ///
+    /// A `Recurrence` if the input is valid, otherwise a `ScheduleError` explaining the problem.
+    pub fn parse(input: &str, first_date: NaiveDate) -> Result<Recurrence, ScheduleError> {
+        let input = crate::utils::normalize_text(input);
+
+        if let Some(date) = ["%Y-%m-%d", "%d.%m.%Y"]
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(&input, format).ok())
+        {
+            if date < first_date {
+                return Err(ScheduleError::PastDate);
+            }
+            return Ok(Recurrence::Once { date });
+        }
+
+        let mut tokens = input.split_whitespace();
+        match tokens.next() {
+            Some(first) if first == "every" || first.starts_with("кажд") => {}
+            _ => return Err(ScheduleError::UnknownRule(input)),
+        }
+
+        let (mut weekday, mut week, mut day) = (None, None, None);
+        for token in tokens {
+            if let Some(found) = parse_weekday(token) {
+                weekday = Some(found);
+            } else if let Some(found) = parse_week(token) {
+                week = Some(found);
+            } else if let Some(found) = parse_day(token) {
+                day = Some(found);
+            }
+        }
+
+        match (week, weekday, day) {
+            (None, Some(weekday), None) => Ok(Recurrence::Weekly { weekday }),
+            (Some(0), Some(weekday), None) => Ok(Recurrence::LastWeekday { weekday }),
+            (Some(week), Some(weekday), None) => Ok(Recurrence::MonthlyWeekday { week, weekday }),
+            (None, None, Some(day)) => Ok(Recurrence::MonthlyDay { day }),
+            _ => Err(ScheduleError::UnknownRule(input)),
+        }
+    }
+
+    /// Returns the description of the rule in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the description.
+    ///
+    /// # Returns
+    ///
+    /// A `String` describing when the event occurs, e.g. "every first Friday of the month".
+    pub fn describe(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        let weekday_name = |weekday: Weekday| tr.weekdays[weekday.num_days_from_monday() as usize];
+        match *self {
+            Recurrence::Once { date } => fill(
+                tr.recurrence_once,
+                &[("date", &date.format("%d.%m.%Y").to_string())],
+            ),
+            Recurrence::Weekly { weekday } => {
+                fill(tr.recurrence_weekly, &[("weekday", &weekday_name(weekday))])
+            }
+            Recurrence::MonthlyDay { day } => fill(tr.recurrence_monthly_day, &[("day", &day)]),
+            Recurrence::MonthlyWeekday { week, weekday } => fill(
+                tr.recurrence_monthly_weekday,
+                &[
+                    ("week", &tr.week_ordinals[week as usize - 1]),
+                    ("weekday", &weekday_name(weekday)),
+                ],
+            ),
+            Recurrence::LastWeekday { weekday } => fill(
+                tr.recurrence_last_weekday,
+                &[("weekday", &weekday_name(weekday))],
+            ),
+        }
+    }
+}
+
+/// Represents an error that can occur when scheduling an event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleError {
+    /// The rule and the title are not separated by a comma.
+    MissingSeparator,
+    /// The title is empty.
+    EmptyTitle,
+    /// The title is longer than allowed.
+    TitleTooLong,
+    /// The date of the one-off event has already passed, or its reminders have already been sent.
+    PastDate,
+    /// The rule could not be recognized.
+    UnknownRule(String),
+}
+
+impl ScheduleError {
+    /// Returns the explanation of the error in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the explanation.
+    ///
+    /// # Returns
+    ///
+    /// A `String` explaining what is wrong with the input.
+    pub fn describe(&self, lang: Language) -> String {
+        let tr = lang.messages();
+        match self {
+            ScheduleError::MissingSeparator => tr.schedule_missing_separator.to_string(),
+            ScheduleError::EmptyTitle => tr.schedule_empty_title.to_string(),
+            ScheduleError::TitleTooLong => {
+                fill(tr.schedule_title_too_long, &[("max", &TITLE_MAX_LEN)])
+            }
+            ScheduleError::PastDate => tr.schedule_past_date.to_string(),
+            ScheduleError::UnknownRule(rule) => fill(tr.schedule_unknown_rule, &[("rule", rule)]),
+        }
+    }
+}
+
+/// Represents a one-off or recurring event scheduled by the administrators of the chat.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    /// The ID of the event, unique within the chat.
+    pub id: u32,
+    /// The title of the event shown in the reminder.
+    pub title: String,
+    /// The rule which defines the days the event occurs on.
+    pub recurrence: Recurrence,
+}
+
+impl ScheduledEvent {
+    /// Parses the event typed by an administrator in the format "rule, title",
+    /// e.g. "every first friday, Team lunch".
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input string to parse.
+    /// * `first_date` - The first date the reminders are still to be sent on.
+    ///
+    /// # Returns
+    ///
+    /// A `ScheduledEvent` without an ID if the input is valid, otherwise a `ScheduleError`.
+    pub fn parse(input: &str, first_date: NaiveDate) -> Result<ScheduledEvent, ScheduleError> {
+        let (rule, title) = input
+            .split_once(',')
+            .ok_or(ScheduleError::MissingSeparator)?;
+        let recurrence = Recurrence::parse(rule, first_date)?;
+
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(ScheduleError::EmptyTitle);
+        }
+        if title.chars().count() > TITLE_MAX_LEN {
+            return Err(ScheduleError::TitleTooLong);
+        }
+
+        Ok(ScheduledEvent {
+            id: 0,
+            title: title.to_string(),
+            recurrence,
+        })
+    }
+}
+
+/// Parses the name of the weekday in English or Russian in any case, e.g. "friday", "fri" or
+/// "пятницу".
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    const NAMES: [(&str, &str, Weekday); 7] = [
+        ("monday", "понедельник", Weekday::Mon),
+        ("tuesday", "вторник", Weekday::Tue),
+        ("wednesday", "сред", Weekday::Wed),
+        ("thursday", "четверг", Weekday::Thu),
+        ("friday", "пятниц", Weekday::Fri),
+        ("saturday", "суббот", Weekday::Sat),
+        ("sunday", "воскресень", Weekday::Sun),
+    ];
+    NAMES
+        .iter()
+        .find(|(en, ru, _)| token.starts_with(en) || token == &en[..3] || token.starts_with(ru))
+        .map(|(_, _, weekday)| *weekday)
+}
+
+/// Parses the ordinal number of the week of the month, e.g. "first" or "вторую".
+/// The last week is returned as 0.
+fn parse_week(token: &str) -> Option<u32> {
+    const STEMS: [(&str, &str, u32); 5] = [
+        ("first", "перв", 1),
+        ("second", "втор", 2),
+        ("third", "трет", 3),
+        ("fourth", "четверт", 4),
+        ("last", "последн", 0),
+    ];
+    STEMS
+        .iter()
+        .find(|(en, ru, _)| token.starts_with(en) || token.starts_with(ru))
+        .map(|(_, _, week)| *week)
+}
+
+/// Parses the day of the month, e.g. "15" or "15-е".
+fn parse_day(token: &str) -> Option<u32> {
+    let digits = token
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    digits
+        .parse::<u32>()
+        .ok()
+        .filter(|day| (1..=31).contains(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_one_off_dates() {
+        let first_date = date(2026, 3, 10);
+        let once = Recurrence::Once {
+            date: date(2026, 12, 20),
+        };
+        assert_eq!(Recurrence::parse("2026-12-20", first_date), Ok(once));
+        assert_eq!(Recurrence::parse("20.12.2026", first_date), Ok(once));
+        assert_eq!(
+            Recurrence::parse("2026-03-10", first_date),
+            Ok(Recurrence::Once { date: first_date })
+        );
+        assert_eq!(
+            Recurrence::parse("2026-03-09", first_date),
+            Err(ScheduleError::PastDate)
+        );
+    }
+
+    #[test]
+    fn rejects_today_after_the_reminders_are_sent() {
+        // After the daily run, the first date the reminders are sent on is tomorrow.
+        let first_date = date(2026, 3, 11);
+        assert_eq!(
+            Recurrence::parse("10.03.2026", first_date),
+            Err(ScheduleError::PastDate)
+        );
+    }
+
+    #[test]
+    fn parses_recurring_rules() {
+        let first_date = date(2026, 3, 10);
+        let cases = [
+            (
+                "every friday",
+                Recurrence::Weekly {
+                    weekday: Weekday::Fri,
+                },
+            ),
+            (
+                "Every Fri",
+                Recurrence::Weekly {
+                    weekday: Weekday::Fri,
+                },
+            ),
+            (
+                "каждый вторник",
+                Recurrence::Weekly {
+                    weekday: Weekday::Tue,
+                },
+            ),
+            (
+                "каждую среду",
+                Recurrence::Weekly {
+                    weekday: Weekday::Wed,
+                },
+            ),
+            (
+                "every first friday",
+                Recurrence::MonthlyWeekday {
+                    week: 1,
+                    weekday: Weekday::Fri,
+                },
+            ),
+            (
+                "каждую вторую среду",
+                Recurrence::MonthlyWeekday {
+                    week: 2,
+                    weekday: Weekday::Wed,
+                },
+            ),
+            (
+                "каждый четвертый четверг",
+                Recurrence::MonthlyWeekday {
+                    week: 4,
+                    weekday: Weekday::Thu,
+                },
+            ),
+            (
+                "every last friday",
+                Recurrence::LastWeekday {
+                    weekday: Weekday::Fri,
+                },
+            ),
+            (
+                "каждое последнее воскресенье",
+                Recurrence::LastWeekday {
+                    weekday: Weekday::Sun,
+                },
+            ),
+            ("every 15", Recurrence::MonthlyDay { day: 15 }),
+            ("каждое 31-е", Recurrence::MonthlyDay { day: 31 }),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                Recurrence::parse(input, first_date),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_rules() {
+        let first_date = date(2026, 3, 10);
+        for input in [
+            "friday",
+            "every",
+            "every 32",
+            "every first",
+            "every 15 friday",
+        ] {
+            assert!(
+                matches!(
+                    Recurrence::parse(input, first_date),
+                    Err(ScheduleError::UnknownRule(_))
+                ),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn monthly_day_falls_back_to_the_last_day_of_shorter_months() {
+        let rule = Recurrence::MonthlyDay { day: 31 };
+        assert!(rule.occurs_on(date(2026, 1, 31)));
+        assert!(rule.occurs_on(date(2026, 2, 28)));
+        assert!(rule.occurs_on(date(2028, 2, 29)));
+        assert!(!rule.occurs_on(date(2028, 2, 28)));
+        assert!(rule.occurs_on(date(2026, 4, 30)));
+        assert!(!rule.occurs_on(date(2026, 4, 29)));
+        assert!(!rule.occurs_on(date(2026, 5, 30)));
+    }
+
+    #[test]
+    fn monthly_weekday_occurs_in_the_given_week() {
+        // The Wednesdays of March 2026 are the 4th, 11th, 18th and 25th.
+        let rule = Recurrence::MonthlyWeekday {
+            week: 2,
+            weekday: Weekday::Wed,
+        };
+        assert!(rule.occurs_on(date(2026, 3, 11)));
+        assert!(!rule.occurs_on(date(2026, 3, 4)));
+        assert!(!rule.occurs_on(date(2026, 3, 18)));
+        assert!(!rule.occurs_on(date(2026, 3, 12)));
+    }
+
+    #[test]
+    fn last_weekday_occurs_in_the_last_week() {
+        // The last Friday of February 2026 is the 27th, of May 2026 is the 29th.
+        let rule = Recurrence::LastWeekday {
+            weekday: Weekday::Fri,
+        };
+        assert!(rule.occurs_on(date(2026, 2, 27)));
+        assert!(!rule.occurs_on(date(2026, 2, 20)));
+        assert!(rule.occurs_on(date(2026, 5, 29)));
+        assert!(!rule.occurs_on(date(2026, 5, 22)));
+        assert_eq!(rule.next_date(date(2026, 2, 28)), Some(date(2026, 3, 27)));
+    }
+
+    #[test]
+    fn one_off_event_is_finished_after_its_date() {
+        let rule = Recurrence::Once {
+            date: date(2026, 12, 20),
+        };
+        assert!(rule.occurs_on(date(2026, 12, 20)));
+        assert!(!rule.is_finished(date(2026, 12, 19)));
+        assert!(rule.is_finished(date(2026, 12, 20)));
+        assert_eq!(rule.next_date(date(2026, 12, 21)), None);
+    }
+}
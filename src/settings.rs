@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use teloxide::types::{MessageId, UserId};
+
+use crate::card::CardTheme;
+use crate::greeting::{DigestPeriod, GreetingKind};
+use crate::{Birthday, EventKind, Language};
+
+/// Represents the order of the day and month in numeric dates, e.g. "05.03" or "5/3".
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum DateOrder {
+    /// The day goes first, e.g. "05.03" is the 5th of March.
+    #[default]
+    DayMonth,
+    /// The month goes first, e.g. "03/05" is the 5th of March.
+    MonthDay,
+}
+
+/// Represents the rule of delivering the reminders about the people with a tag.
+/// The rules are ordered from the least to the most restrictive.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryRule {
+    /// All reminders are delivered as usual.
+    #[default]
+    All,
+    /// Only the advance reminders are delivered, the greeting on the day is not posted.
+    Advance,
+    /// The reminders are delivered only to the subscribers in private messages.
+    Private,
+    /// No reminders are delivered.
+    Off,
+}
+
+impl DeliveryRule {
+    /// All rules in the order they are shown to users.
+    pub const ALL: [DeliveryRule; 4] = [
+        DeliveryRule::All,
+        DeliveryRule::Advance,
+        DeliveryRule::Private,
+        DeliveryRule::Off,
+    ];
+
+    /// Returns the English word used to refer to the rule in commands, e.g. "advance".
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            DeliveryRule::All => "all",
+            DeliveryRule::Advance => "advance",
+            DeliveryRule::Private => "private",
+            DeliveryRule::Off => "off",
+        }
+    }
+
+    /// Parses the rule typed by an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The name of the rule in English or Russian, e.g. "advance" or "заранее".
+    ///
+    /// # Returns
+    ///
+    /// Some rule if the input is recognized, otherwise None.
+    pub fn parse(input: &str) -> Option<DeliveryRule> {
+        match crate::utils::normalize_text(input).as_str() {
+            "all" | "все" => Some(DeliveryRule::All),
+            "advance" | "заранее" => Some(DeliveryRule::Advance),
+            "private" | "лично" => Some(DeliveryRule::Private),
+            "off" | "выкл" => Some(DeliveryRule::Off),
+            _ => None,
+        }
+    }
+
+    /// Returns the description of the rule in the given language.
+    pub fn describe(&self, lang: Language) -> &'static str {
+        let tr = lang.messages();
+        match self {
+            DeliveryRule::All => tr.delivery_all,
+            DeliveryRule::Advance => tr.delivery_advance,
+            DeliveryRule::Private => tr.delivery_private,
+            DeliveryRule::Off => tr.delivery_off,
+        }
+    }
+
+    /// Returns whether the reminder of the given kind is delivered under the rule.
+    pub fn allows(&self, kind: GreetingKind) -> bool {
+        match self {
+            DeliveryRule::All | DeliveryRule::Private => true,
+            DeliveryRule::Advance => kind == GreetingKind::Advance,
+            DeliveryRule::Off => false,
+        }
+    }
+}
+
+/// Represents the way the greeting cards are sent on birthdays.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardMode {
+    /// Only the text greetings are sent.
+    #[default]
+    Off,
+    /// The card is sent with the greeting as its caption instead of the text message.
+    Photo,
+    /// The card is sent after the text greeting.
+    Both,
+}
+
+impl CardMode {
+    /// All modes in the order they are shown to users.
+    pub const ALL: [CardMode; 3] = [CardMode::Off, CardMode::Photo, CardMode::Both];
+
+    /// Returns the English word used to refer to the mode in commands, e.g. "photo".
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            CardMode::Off => "off",
+            CardMode::Photo => "photo",
+            CardMode::Both => "both",
+        }
+    }
+
+    /// Parses the mode typed by an administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The name of the mode in English or Russian, e.g. "photo" or "фото".
+    ///
+    /// # Returns
+    ///
+    /// Some mode if the input is recognized, otherwise None.
+    pub fn parse(input: &str) -> Option<CardMode> {
+        match crate::utils::normalize_text(input).as_str() {
+            "off" | "выкл" => Some(CardMode::Off),
+            "photo" | "фото" => Some(CardMode::Photo),
+            "both" | "вместе" => Some(CardMode::Both),
+            _ => None,
+        }
+    }
+
+    /// Returns the description of the mode in the given language.
+    pub fn describe(&self, lang: Language) -> &'static str {
+        let tr = lang.messages();
+        match self {
+            CardMode::Off => tr.card_mode_off,
+            CardMode::Photo => tr.card_mode_photo,
+            CardMode::Both => tr.card_mode_both,
+        }
+    }
+}
+
+/// Represents the per-chat settings of the bot.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Whether the list of birthdays is hidden from regular members of the chat.
+    pub is_private: bool,
+    /// Whether members of the chat can add their own birthdays.
+    pub allow_self_registration: bool,
+    /// The order of the day and month in numeric dates.
+    pub date_order: DateOrder,
+    /// The language of the messages sent to the chat.
+    pub language: Language,
+    /// The number of days before the birthday to send the advance reminder, 0 if disabled.
+    pub advance_days: u32,
+    /// The templates of the greetings sent on the birthday.
+    pub greetings: Vec<String>,
+    /// The templates of the reminders sent before the birthday.
+    pub advance_greetings: Vec<String>,
+    /// The templates of the greetings sent on the day of other events by the kind of the event.
+    pub event_greetings: HashMap<EventKind, Vec<String>>,
+    /// The templates of the reminders sent before other events by the kind of the event.
+    pub event_advance_greetings: HashMap<EventKind, Vec<String>>,
+    /// Whether the reminders of one day are combined into a single message.
+    pub combine_reminders: bool,
+    /// Whether the digest of the birthdays of the week is sent on Mondays.
+    pub weekly_digest: bool,
+    /// Whether the digest of the birthdays of the month is sent on the first day of the month.
+    pub monthly_digest: bool,
+    /// The users who receive the reminders of the chat in private messages.
+    pub subscribers: Vec<UserId>,
+    /// Whether the advance reminders are sent only to the subscribers instead of the chat.
+    pub surprise_mode: bool,
+    /// Whether the name days of the people are announced according to the Orthodox calendar.
+    pub name_days: bool,
+    /// The rules of delivering the reminders about the people by their tags.
+    pub tag_rules: HashMap<String, DeliveryRule>,
+    /// The forum topic the reminders, digests and collections are posted in, or None for the
+    /// General topic.
+    pub topic: Option<i32>,
+    /// Whether the greeting of the day is pinned in the chat until the next day.
+    pub pin_greeting: bool,
+    /// The pinned greeting to unpin on the next day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_greeting: Option<MessageId>,
+    /// The way the greeting cards are sent on birthdays.
+    pub card_mode: CardMode,
+    /// The background theme of the greeting cards.
+    pub card_theme: CardTheme,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            is_private: false,
+            allow_self_registration: true,
+            date_order: DateOrder::default(),
+            language: Language::default(),
+            advance_days: 0,
+            greetings: Vec::new(),
+            advance_greetings: Vec::new(),
+            event_greetings: HashMap::new(),
+            event_advance_greetings: HashMap::new(),
+            combine_reminders: false,
+            weekly_digest: false,
+            monthly_digest: false,
+            subscribers: Vec::new(),
+            surprise_mode: false,
+            name_days: false,
+            tag_rules: HashMap::new(),
+            topic: None,
+            pin_greeting: false,
+            pinned_greeting: None,
+            card_mode: CardMode::default(),
+            card_theme: CardTheme::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Returns the greeting templates of the given kind for the events of the given kind.
+    pub fn templates(&self, kind: GreetingKind, event: EventKind) -> &[String] {
+        match (kind, event) {
+            (GreetingKind::SameDay, EventKind::Birthday) => &self.greetings,
+            (GreetingKind::Advance, EventKind::Birthday) => &self.advance_greetings,
+            (GreetingKind::SameDay, event) => self
+                .event_greetings
+                .get(&event)
+                .map_or(&[], |templates| templates.as_slice()),
+            (GreetingKind::Advance, event) => self
+                .event_advance_greetings
+                .get(&event)
+                .map_or(&[], |templates| templates.as_slice()),
+        }
+    }
+
+    /// Returns the rule of delivering the reminders about the given person.
+    /// If the person has several tags with rules, the most restrictive rule applies.
+    pub fn delivery_rule(&self, birthday: &Birthday) -> DeliveryRule {
+        self.tag_rules
+            .iter()
+            .filter(|(tag, _)| birthday.has_tag(tag))
+            .map(|(_, rule)| *rule)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the digest of the given period is enabled.
+    pub fn digest(&self, period: DigestPeriod) -> bool {
+        match period {
+            DigestPeriod::Weekly => self.weekly_digest,
+            DigestPeriod::Monthly => self.monthly_digest,
+        }
+    }
+
+    /// Returns the mutable flag of the digest of the given period.
+    pub fn digest_mut(&mut self, period: DigestPeriod) -> &mut bool {
+        match period {
+            DigestPeriod::Weekly => &mut self.weekly_digest,
+            DigestPeriod::Monthly => &mut self.monthly_digest,
+        }
+    }
+
+    /// Returns the mutable greeting templates of the given kind for the events of the given kind.
+    pub fn templates_mut(&mut self, kind: GreetingKind, event: EventKind) -> &mut Vec<String> {
+        match (kind, event) {
+            (GreetingKind::SameDay, EventKind::Birthday) => &mut self.greetings,
+            (GreetingKind::Advance, EventKind::Birthday) => &mut self.advance_greetings,
+            (GreetingKind::SameDay, event) => self.event_greetings.entry(event).or_default(),
+            (GreetingKind::Advance, event) => {
+                self.event_advance_greetings.entry(event).or_default()
+            }
+        }
+    }
+}
@@ -1,19 +1,42 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use chrono::{Duration, Utc};
-use teloxide::prelude::{ChatId, Requester};
+use chrono::{Duration, NaiveDate, Timelike, Utc};
+use teloxide::payloads::{
+    PinChatMessageSetters, SendMessageSetters, SendPhotoSetters, SendPollSetters,
+    UnpinChatMessageSetters,
+};
+use teloxide::prelude::{ChatId, Message, Requester, ResponseResult};
+use teloxide::types::{InlineKeyboardMarkup, InputFile, ParseMode};
+use teloxide::utils::html;
 use teloxide::Bot;
 use tokio::task::JoinHandle;
 
+use crate::card::{self, CardTheme};
+use crate::greeting::{
+    render_digest, render_greeting, render_period_digest, DigestPeriod, GreetingKind,
+};
+use crate::i18n::fill;
+use crate::{nameday, wishlist, Birthday, CardMode, DeliveryRule, Language, Settings};
+
 /// Constant for the birthday reminder task period in seconds.
 const BIRTHDAY_REMINDER_TASK_PERIOD_SEC: i64 = 60 * 60 * 24;
 
+/// The hour of the day in UTC when the birthday reminders are sent.
+const BIRTHDAY_REMINDER_HOUR: u32 = 7;
+
 /// Constant for the backup task period in seconds.
 const BACKUP_TASK_PERIOD_SEC: i64 = 60 * 60 * 24;
 
 /// Constant for the health check task period in seconds.
 const HEALTH_CHECK_TASK_PERIOD_SEC: i64 = 60 * 60 * 24;
 
+/// Constant for the digest task period in seconds.
+const DIGEST_TASK_PERIOD_SEC: i64 = 60 * 60 * 24;
+
+/// The maximum length of a photo caption in Telegram.
+const CAPTION_MAX_LEN: usize = 1024;
+
 /// The task manager for the bot.
 pub struct Manager {
     /// The birthday reminder task.
@@ -22,6 +45,10 @@ pub struct Manager {
     health_check: JoinHandle<()>,
     /// The daily backup task.
     daily_backup: JoinHandle<()>,
+    /// The weekly digest task.
+    weekly_digest: JoinHandle<()>,
+    /// The monthly digest task.
+    monthly_digest: JoinHandle<()>,
 }
 
 impl Manager {
@@ -32,6 +59,8 @@ impl Manager {
     /// * `birthday_reminder` - The birthday reminder task.
     /// * `health_check` - The health check task.
     /// * `daily_backup` - The daily backup task.
+    /// * `weekly_digest` - The weekly digest task.
+    /// * `monthly_digest` - The monthly digest task.
     ///
     /// # Returns
     ///
@@ -40,11 +69,15 @@ impl Manager {
         birthday_reminder: JoinHandle<()>,
         health_check: JoinHandle<()>,
         daily_backup: JoinHandle<()>,
+        weekly_digest: JoinHandle<()>,
+        monthly_digest: JoinHandle<()>,
     ) -> Self {
         Self {
             birthday_reminder,
             health_check,
             daily_backup,
+            weekly_digest,
+            monthly_digest,
         }
     }
 
@@ -62,6 +95,16 @@ impl Manager {
     pub fn is_daily_backup_active(&self) -> bool {
         !self.daily_backup.is_finished()
     }
+
+    /// Returns whether the weekly digest task is active.
+    pub fn is_weekly_digest_active(&self) -> bool {
+        !self.weekly_digest.is_finished()
+    }
+
+    /// Returns whether the monthly digest task is active.
+    pub fn is_monthly_digest_active(&self) -> bool {
+        !self.monthly_digest.is_finished()
+    }
 }
 
 /// Sends a health check message to the maintainer of the bot.
@@ -136,6 +179,21 @@ pub async fn daily_backup_task(map: super::BirthdaysMapThreadSafe, backup_path:
 ///
 /// This function sends reminders about upcoming birthdays to chats
 /// with an active bot state. The reminders are sent at 7:00 AM UTC daily.
+/// The greetings are rendered from the templates of the chat, and the advance
+/// reminders are sent if they are enabled for the chat. If the chat combines
+/// the reminders, all of them are sent as a single digest message. The
+/// subscribers of the chat receive the reminders in private messages. In the
+/// surprise mode, the advance reminders are sent only to the subscribers.
+/// The advance reminders have buttons to show the wishlists of the people.
+/// The one-off and recurring events scheduled for the day are reminded about as
+/// well, and the one-off events are forgotten afterwards. If the chat has opted
+/// in, the name days of the people are announced according to the calendar.
+/// The delivery of the reminders follows the rules of the tags of the people.
+/// In supergroups with forum topics, the reminders are posted in the chosen topic.
+/// If the chat has opted in, the greeting is pinned until the next day, and the greeting cards
+/// are sent on birthdays instead of or in addition to the text greetings.
+/// The celebration polls are posted with the advance reminders, and their results are summarized
+/// the day before the birthdays.
 pub async fn send_birthday_reminders(
     bot: Bot,
     birthdays_map: super::BirthdaysMapThreadSafe,
@@ -145,41 +203,581 @@ pub async fn send_birthday_reminders(
         let now = Utc::now().naive_utc();
         let next_run = (now + Duration::seconds(BIRTHDAY_REMINDER_TASK_PERIOD_SEC))
             .date()
-            .and_hms_opt(7, 0, 0)
+            .and_hms_opt(BIRTHDAY_REMINDER_HOUR, 0, 0)
             .unwrap_or_default();
         let duration_until_next_run = (next_run - now).to_std().unwrap_or_default();
 
         // Sleep until the next reminder time.
         tokio::time::sleep(duration_until_next_run).await;
 
+        let today = Utc::now().date_naive();
         let mut output = Vec::new();
+        let mut private_output = Vec::new();
+        let mut unpin = Vec::new();
+        let mut card_styles = HashMap::new();
+        let mut poll_posts = Vec::new();
+        let mut poll_results = Vec::new();
         {
             let b_map = birthdays_map.read().await;
 
             for (chat_id, (state, birthdays)) in b_map.iter() {
+                let settings = b_map.get_settings(chat_id);
+                if let Some(message_id) = settings.pinned_greeting {
+                    unpin.push((*chat_id, message_id));
+                }
+                card_styles.insert(
+                    *chat_id,
+                    (settings.card_mode, settings.card_theme, settings.language),
+                );
+
                 if super::State::Active == *state {
-                    for birthday in birthdays.iter() {
-                        if birthday.date == Utc::now().format("%d-%m").to_string() {
-                            let username_text = if !birthday.username.is_empty() {
-                                format!("({})", birthday.username)
-                            } else {
-                                "".into()
-                            };
-
-                            let text = format!(
-                                "Поздравьте сегодня замечательного человека с днем рождения {} {}!🎉",
-                                birthday.name, username_text
-                            );
-                            output.push((*chat_id, text));
+                    // Remind about the one-off and recurring events scheduled for today.
+                    for event in b_map
+                        .events(chat_id)
+                        .iter()
+                        .filter(|event| event.recurrence.occurs_on(today))
+                    {
+                        let text = fill(
+                            settings.language.messages().scheduled_event_reminder,
+                            &[("title", &html::escape(&event.title))],
+                        );
+                        output.push((*chat_id, settings.topic, text, None, false, Vec::new()));
+                    }
+
+                    // Announce the name days of the people named after the saints of the day.
+                    let celebrants = if settings.name_days {
+                        nameday::celebrants(birthdays, today)
+                            .into_iter()
+                            .filter(|birthday| {
+                                settings.delivery_rule(birthday) == DeliveryRule::All
+                            })
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    if !celebrants.is_empty() {
+                        let names = celebrants
+                            .iter()
+                            .map(|birthday| birthday.mention())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        let text = fill(
+                            settings.language.messages().name_day_calendar_reminder,
+                            &[("names", &names)],
+                        );
+                        output.push((*chat_id, settings.topic, text, None, false, Vec::new()));
+                    }
+
+                    // Post the polls once the advance reminders are due, and stop them the day
+                    // before the birthdays. Like the advance reminders, the polls and their
+                    // results are kept out of the chat in the surprise mode and for the people
+                    // whose reminders are private. The polls of the removed birthdays are dropped.
+                    for poll in b_map.polls(chat_id) {
+                        let upcoming = birthdays
+                            .iter()
+                            .find(|birthday| poll.is_about(birthday))
+                            .and_then(|birthday| {
+                                birthday.days_until(today).map(|days| (birthday, days))
+                            });
+                        let in_chat = |birthday: &Birthday| {
+                            !settings.surprise_mode
+                                && matches!(
+                                    settings.delivery_rule(birthday),
+                                    DeliveryRule::All | DeliveryRule::Advance
+                                )
+                        };
+                        match upcoming {
+                            Some((birthday, days)) if days > 1 => {
+                                if poll.message_id.is_none()
+                                    && days <= settings.advance_days as i64
+                                    && in_chat(birthday)
+                                {
+                                    poll_posts.push((
+                                        *chat_id,
+                                        settings.topic,
+                                        poll.id,
+                                        poll.question.clone(),
+                                        poll.options.clone(),
+                                    ));
+                                }
+                            }
+                            Some((birthday, _)) => poll_results.push((
+                                *chat_id,
+                                settings.topic,
+                                settings.language,
+                                poll.id,
+                                (poll.message_id.is_some() && in_chat(birthday))
+                                    .then(|| birthday.name.clone()),
+                            )),
+                            None => poll_results.push((
+                                *chat_id,
+                                settings.topic,
+                                settings.language,
+                                poll.id,
+                                None,
+                            )),
+                        }
+                    }
+
+                    let due = birthdays
+                        .iter()
+                        .filter_map(|birthday| match birthday.days_until(today) {
+                            Some(0) => Some((GreetingKind::SameDay, birthday)),
+                            Some(days)
+                                if settings.advance_days > 0
+                                    && days == settings.advance_days as i64 =>
+                            {
+                                Some((GreetingKind::Advance, birthday))
+                            }
+                            _ => None,
+                        })
+                        .filter(|(kind, birthday)| settings.delivery_rule(birthday).allows(*kind))
+                        .collect::<Vec<_>>();
+
+                    // Deliver the reminders to the subscribers, except for their own birthdays.
+                    for subscriber in settings.subscribers.iter() {
+                        let subscriber_due = due
+                            .iter()
+                            .filter(|(_, birthday)| birthday.user_id != Some(*subscriber))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        if subscriber_due.is_empty() {
+                            continue;
                         }
+                        let private_chat_id = ChatId(subscriber.0 as i64);
+                        let lang = b_map.get_settings(&private_chat_id).language;
+                        let text = render_digest(&subscriber_due, today, lang);
+                        let keyboard = wishlist::show_keyboard(&subscriber_due, &b_map, lang);
+                        private_output.push((*subscriber, *chat_id, lang, text, keyboard));
+                    }
+
+                    // Keep the advance reminders a surprise for the person whose birthday it is,
+                    // and the reminders about the people with private tags out of the chat.
+                    let due = due
+                        .into_iter()
+                        .filter(|(kind, _)| {
+                            !settings.surprise_mode || *kind == GreetingKind::SameDay
+                        })
+                        .filter(|(_, birthday)| {
+                            settings.delivery_rule(birthday) != DeliveryRule::Private
+                        })
+                        .collect::<Vec<_>>();
+
+                    // Combine the reminders into a single digest to avoid spamming the chat.
+                    if settings.combine_reminders && due.len() > 1 {
+                        let text = render_digest(&due, today, settings.language);
+                        let keyboard = wishlist::show_keyboard(&due, &b_map, settings.language);
+                        let pin = settings.pin_greeting
+                            && due.iter().any(|(kind, _)| *kind == GreetingKind::SameDay);
+                        let cards = card_birthdays(&due, &settings);
+                        output.push((*chat_id, settings.topic, text, keyboard, pin, cards));
+                        continue;
+                    }
+
+                    for (kind, birthday) in due {
+                        let text = render_greeting(
+                            settings.templates(kind, birthday.kind),
+                            kind,
+                            birthday,
+                            today,
+                            settings.language,
+                        );
+                        let keyboard =
+                            wishlist::show_keyboard(&[(kind, birthday)], &b_map, settings.language);
+                        let pin = settings.pin_greeting && kind == GreetingKind::SameDay;
+                        let cards = card_birthdays(&[(kind, birthday)], &settings);
+                        output.push((*chat_id, settings.topic, text, keyboard, pin, cards));
+                    }
+                }
+            }
+        }
+
+        // Unpin the greetings of the previous day.
+        let mut pinned = HashMap::new();
+        for (chat_id, message_id) in unpin {
+            if let Err(e) = bot.unpin_chat_message(chat_id).message_id(message_id).await {
+                log::warn!("Failed to unpin the greeting in chat id {}: {}", chat_id, e);
+            }
+            pinned.insert(chat_id, None);
+        }
+
+        // Send the reminders and silently pin the first greeting of the day. A failure in one chat
+        // does not stop the reminders of the other chats.
+        let mut pin_failed = Vec::new();
+        let mut lost_topics = Vec::new();
+        for (chat_id, topic, text, keyboard, pin, cards) in output {
+            let topic = topic.filter(|_| !lost_topics.contains(&chat_id));
+            let (card_mode, card_theme, lang) = card_styles[&chat_id];
+            let cards = render_cards(cards, card_theme, lang, today).await;
+            // The card replaces the text message if the greeting fits in the caption.
+            let as_caption = card_mode == CardMode::Photo
+                && cards.len() == 1
+                && text.chars().count() <= CAPTION_MAX_LEN;
+            let mut cards = cards
+                .into_iter()
+                .map(|card| InputFile::memory(card).file_name("card.png"));
+            let caption_card = if as_caption { cards.next() } else { None };
+
+            let mut result = send_reminder(
+                &bot,
+                chat_id,
+                topic,
+                &text,
+                keyboard.as_ref(),
+                caption_card.as_ref(),
+            )
+            .await;
+            // Fall back to the General topic if the chosen topic is deleted or closed.
+            if topic.is_some() && matches!(&result, Err(e) if crate::utils::is_topic_error(e)) {
+                log::warn!("The topic of chat id {} is unavailable", chat_id);
+                lost_topics.push(chat_id);
+                result = send_reminder(
+                    &bot,
+                    chat_id,
+                    None,
+                    &text,
+                    keyboard.as_ref(),
+                    caption_card.as_ref(),
+                )
+                .await;
+            }
+            let topic = topic.filter(|_| !lost_topics.contains(&chat_id));
+            let sent = match result {
+                Ok(sent) => sent,
+                Err(e) => {
+                    log::error!(
+                        "Error during sending reminder to chat id {}: {}",
+                        chat_id,
+                        e
+                    );
+                    continue;
+                }
+            };
+            for card in cards {
+                let mut request = bot.send_photo(chat_id, card);
+                if let Some(topic) = topic {
+                    request = request.message_thread_id(topic);
+                }
+                if let Err(e) = request.await {
+                    log::error!("Error during sending greeting card: {}", e);
+                }
+            }
+
+            if !pin
+                || matches!(pinned.get(&chat_id), Some(Some(_)))
+                || pin_failed.contains(&chat_id)
+            {
+                continue;
+            }
+            match bot
+                .pin_chat_message(chat_id, sent.id)
+                .disable_notification(true)
+                .await
+            {
+                Ok(_) => {
+                    pinned.insert(chat_id, Some(sent.id));
+                }
+                Err(e) => {
+                    log::warn!("Failed to pin the greeting in chat id {}: {}", chat_id, e);
+                    pinned.insert(chat_id, None);
+                    pin_failed.push(chat_id);
+                }
+            }
+        }
+
+        // Post in the General topic from now on in the chats whose topics are deleted or closed,
+        // remember the pinned greetings, and stop pinning in the chats where the bot lacks the
+        // rights to, so the administrators are notified only once.
+        let mut notices = Vec::new();
+        if !pinned.is_empty() || !lost_topics.is_empty() {
+            let mut b_map = birthdays_map.write().await;
+            for chat_id in lost_topics.iter() {
+                let mut settings = b_map.get_settings(chat_id);
+                settings.topic = None;
+                notices.push((*chat_id, None, settings.language.messages().topic_lost));
+                if let Err(err) = b_map.update_settings(chat_id, settings) {
+                    log::error!("Settings not updated for chat id {}: {:?}", chat_id, err);
+                }
+            }
+            for (chat_id, message_id) in pinned {
+                let mut settings = b_map.get_settings(&chat_id);
+                settings.pinned_greeting = message_id;
+                if pin_failed.contains(&chat_id) {
+                    settings.pin_greeting = false;
+                    notices.push((
+                        chat_id,
+                        settings.topic,
+                        settings.language.messages().pin_failed,
+                    ));
+                }
+                if let Err(err) = b_map.update_settings(&chat_id, settings) {
+                    log::error!("Settings not updated for chat id {}: {:?}", chat_id, err);
+                }
+            }
+        }
+        for (chat_id, topic, text) in notices {
+            let mut request = bot.send_message(chat_id, text);
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            if let Err(e) = request.await {
+                log::error!("Error during sending notice: {}", e);
+            }
+        }
+
+        // Post the polls and remember their IDs to track the answers.
+        for (chat_id, topic, id, question, options) in poll_posts {
+            let topic = topic.filter(|_| !lost_topics.contains(&chat_id));
+            let mut request = bot
+                .send_poll(chat_id, question, options)
+                .is_anonymous(false);
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            match request.await {
+                Ok(sent) => {
+                    log::info!("Poll {} posted in chat id {}", id, chat_id);
+                    let mut b_map = birthdays_map.write().await;
+                    if let Some(poll) = b_map.poll_mut(&chat_id, id) {
+                        poll.poll_id = sent.poll().map(|poll| poll.id.clone());
+                        poll.message_id = Some(sent.id);
+                    }
+                }
+                Err(e) => log::error!("Error during sending poll: {}", e),
+            }
+        }
+
+        // Stop the polls of the birthdays of tomorrow and sum up the answers.
+        for (chat_id, topic, lang, id, name) in poll_results {
+            let topic = topic.filter(|_| !lost_topics.contains(&chat_id));
+            let mut poll = match birthdays_map.write().await.remove_poll(&chat_id, id) {
+                Some(poll) => poll,
+                None => continue,
+            };
+            if let Some(message_id) = poll.message_id {
+                if let Err(e) = bot.stop_poll(chat_id, message_id).await {
+                    log::warn!("Failed to stop the poll in chat id {}: {}", chat_id, e);
+                }
+            }
+            // The results are summed up with the current name in case the person was renamed.
+            match name {
+                Some(name) => poll.name = name,
+                None => continue,
+            }
+            let mut request = bot.send_message(chat_id, poll.summary(lang));
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            if let Err(e) = request.await {
+                log::error!("Error during sending poll results: {}", e);
+            }
+        }
+
+        // Forget the one-off events which have already taken place.
+        let removed = birthdays_map.write().await.remove_finished_events(today);
+        if removed > 0 {
+            log::info!("{} finished scheduled events removed", removed);
+        }
+
+        // Send the reminders to the subscribers who are still members of the chats.
+        for (user_id, chat_id, lang, text, keyboard) in private_output {
+            if !crate::utils::is_member(&bot, chat_id, user_id)
+                .await
+                .unwrap_or_default()
+            {
+                log::warn!("User {} is not a member of chat id {}", user_id, chat_id);
+                continue;
+            }
+            let title = crate::utils::get_chat_title(&bot, chat_id).await;
+            let header = fill(
+                lang.messages().subscription_header,
+                &[("chat", &html::escape(&title))],
+            );
+            let request = bot
+                .send_message(ChatId(user_id.0 as i64), format!("{}\n\n{}", header, text))
+                .parse_mode(ParseMode::Html);
+            let result = match keyboard {
+                Some(keyboard) => request.reply_markup(keyboard).await,
+                None => request.await,
+            };
+            match result {
+                Ok(_) => log::info!("Reminder sent to subscriber {}", user_id),
+                Err(e) => log::error!("Error during sending reminder to subscriber: {}", e),
+            }
+        }
+    }
+}
+
+/// Sends the reminder to the chat, as the caption of the card if the card is given.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `chat_id` - The chat ID.
+/// * `topic` - The forum topic to post the reminder in, or None for the General topic.
+/// * `text` - The text of the reminder in HTML.
+/// * `keyboard` - The buttons of the reminder.
+/// * `card` - The greeting card to send the reminder as its caption.
+///
+/// # Returns
+///
+/// A `ResponseResult` with the sent message.
+async fn send_reminder(
+    bot: &Bot,
+    chat_id: ChatId,
+    topic: Option<i32>,
+    text: &str,
+    keyboard: Option<&InlineKeyboardMarkup>,
+    card: Option<&InputFile>,
+) -> ResponseResult<Message> {
+    match card {
+        Some(card) => {
+            let mut request = bot
+                .send_photo(chat_id, card.clone())
+                .caption(text)
+                .parse_mode(ParseMode::Html);
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard.clone());
+            }
+            request.await
+        }
+        None => {
+            let mut request = bot.send_message(chat_id, text).parse_mode(ParseMode::Html);
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard.clone());
+            }
+            request.await
+        }
+    }
+}
+
+/// Returns the people to draw the greeting cards for if the chat has enabled them.
+/// The cards are rendered later, when the lock of the map is released.
+///
+/// # Arguments
+///
+/// * `due` - The birthdays to remind about with the kinds of the reminders.
+/// * `settings` - The settings of the chat.
+///
+/// # Returns
+///
+/// A vector of the birthdays celebrated today, empty if the cards are disabled.
+fn card_birthdays(due: &[(GreetingKind, &Birthday)], settings: &Settings) -> Vec<Birthday> {
+    if settings.card_mode == CardMode::Off {
+        return Vec::new();
+    }
+    due.iter()
+        .filter(|(kind, birthday)| *kind == GreetingKind::SameDay && birthday.kind.is_birthday())
+        .map(|(_, birthday)| (*birthday).clone())
+        .collect()
+}
+
+/// Renders the greeting cards on the threads for blocking work.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays to draw the cards for.
+/// * `theme` - The theme of the cards.
+/// * `lang` - The language of the chat.
+/// * `today` - The current date.
+///
+/// # Returns
+///
+/// A vector of the PNG images of the cards. The cards which could not be rendered are skipped.
+async fn render_cards(
+    birthdays: Vec<Birthday>,
+    theme: CardTheme,
+    lang: Language,
+    today: NaiveDate,
+) -> Vec<Vec<u8>> {
+    let mut cards = Vec::new();
+    for birthday in birthdays {
+        let card =
+            card::render_blocking(move || card::render_birthday(theme, &birthday, today, lang))
+                .await;
+        match card {
+            Ok(card) => cards.push(card),
+            Err(e) => log::error!("Error during rendering greeting card: {}", e),
+        }
+    }
+    cards
+}
+
+/// Returns the first date the birthday reminders are still to be sent on: today before the
+/// reminder time, otherwise tomorrow.
+pub fn first_reminder_date() -> NaiveDate {
+    let now = Utc::now().naive_utc();
+    if now.hour() < BIRTHDAY_REMINDER_HOUR {
+        now.date()
+    } else {
+        now.date() + Duration::days(1)
+    }
+}
+
+/// Sends the digests of the birthdays of the week or the month.
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance.
+/// * `birthdays_map` - A thread-safe map of chat IDs to bot states and birthdays.
+/// * `period` - The period of the digests.
+///
+/// This function sends the digests to chats with an active bot state and the
+/// digest of the given period enabled. The weekly digests are sent on Mondays
+/// and the monthly digests on the first day of the month at 7:05 AM UTC.
+/// In supergroups with forum topics, the digests are posted in the chosen topic.
+pub async fn digest_task(
+    bot: Bot,
+    birthdays_map: super::BirthdaysMapThreadSafe,
+    period: DigestPeriod,
+) {
+    loop {
+        // Calculate the time for the next digest.
+        let now = Utc::now().naive_utc();
+        let next_run = (now + Duration::seconds(DIGEST_TASK_PERIOD_SEC))
+            .date()
+            .and_hms_opt(7, 5, 0)
+            .unwrap_or_default();
+        let duration_until_next_run = (next_run - now).to_std().unwrap_or_default();
+
+        // Sleep until the next digest time.
+        tokio::time::sleep(duration_until_next_run).await;
+
+        let today = Utc::now().date_naive();
+        if !period.is_due(today) {
+            continue;
+        }
+
+        let mut output = Vec::new();
+        {
+            let b_map = birthdays_map.read().await;
+
+            for (chat_id, (state, birthdays)) in b_map.iter() {
+                let settings = b_map.get_settings(chat_id);
+                if super::State::Active == *state && settings.digest(period) {
+                    if let Some(text) = render_period_digest(birthdays, period, today, &settings) {
+                        output.push((*chat_id, settings.topic, text));
                     }
                 }
             }
         }
 
-        // Send the reminders.
-        for (chat_id, text) in output {
-            bot.send_message(chat_id, text).await?;
+        // Send the digests.
+        for (chat_id, topic, text) in output {
+            let mut request = bot.send_message(chat_id, text);
+            if let Some(topic) = topic {
+                request = request.message_thread_id(topic);
+            }
+            match request.await {
+                Ok(_) => log::info!("{:?} digest sent to chat id {}", period, chat_id),
+                Err(e) => log::error!("Error during sending {:?} digest: {}", period, e),
+            }
         }
     }
 }
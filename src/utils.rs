@@ -3,14 +3,19 @@ use std::io::BufRead;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use regex::Regex;
 use teloxide::prelude::{ChatId, Request, Requester, UserId};
-use teloxide::types::Chat;
-use teloxide::{Bot, RequestError};
+use teloxide::types::{
+    Administrator, Chat, ChatKind, ChatMemberKind, ChatPublic, Message, MessageKind,
+    PublicChatKind, PublicChatSupergroup,
+};
+use teloxide::{ApiError, Bot, RequestError};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::RwLock;
 
-use crate::Birthday;
+use crate::Language;
+
+/// The maximum length of a Telegram message in characters.
+pub const MESSAGE_MAX_LEN: usize = 4096;
 
 /// Represents places where bot is used
 pub enum Place {
@@ -19,12 +24,22 @@ pub enum Place {
     Chat,
 }
 
-impl std::fmt::Display for Place {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Place {
+    /// Returns the name of the place in the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language of the name.
+    ///
+    /// # Returns
+    ///
+    /// The name of the place, e.g. "в группе".
+    pub fn name(&self, lang: Language) -> &'static str {
+        let tr = lang.messages();
         match self {
-            Place::Group => write!(f, "в группе"),
-            Place::Channel => write!(f, "в канале"),
-            Place::Chat => write!(f, "в чате"),
+            Place::Group => tr.place_group,
+            Place::Channel => tr.place_channel,
+            Place::Chat => tr.place_chat,
         }
     }
 }
@@ -45,6 +60,63 @@ pub async fn is_admin(bot: &Bot, chat_id: ChatId, user_id: UserId) -> Result<boo
     Ok(admins.iter().any(|admin| admin.user.id == user_id))
 }
 
+/// Function checks that the bot is allowed to pin messages in the chat
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance
+/// * `chat_id` - The chat id
+///
+/// # Returns
+///
+/// A `Result` indicating the bot can pin messages or not.
+pub async fn can_pin_messages(bot: &Bot, chat_id: ChatId) -> Result<bool, RequestError> {
+    let me = bot.get_me().send().await?;
+    let member = bot.get_chat_member(chat_id, me.id).send().await?;
+    Ok(match member.kind {
+        ChatMemberKind::Owner(_) => true,
+        ChatMemberKind::Administrator(Administrator {
+            can_pin_messages, ..
+        }) => can_pin_messages,
+        _ => false,
+    })
+}
+
+/// Function checks that user is a member of the chat
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance
+/// * `chat_id` - The chat id
+/// * `user_id` - The user id
+///
+/// # Returns
+///
+/// A `Result` indicating the user is present in the chat or not.
+pub async fn is_member(bot: &Bot, chat_id: ChatId, user_id: UserId) -> Result<bool, RequestError> {
+    let member = bot.get_chat_member(chat_id, user_id).send().await?;
+    Ok(member.kind.is_present())
+}
+
+/// Function returns the title of the chat
+///
+/// # Arguments
+///
+/// * `bot` - The bot instance
+/// * `chat_id` - The chat id
+///
+/// # Returns
+///
+/// The title of the chat, or its id if the title is not available.
+pub async fn get_chat_title(bot: &Bot, chat_id: ChatId) -> String {
+    bot.get_chat(chat_id)
+        .send()
+        .await
+        .ok()
+        .and_then(|chat| chat.title().map(str::to_string))
+        .unwrap_or_else(|| chat_id.to_string())
+}
+
 /// Function checks that user is maintainer
 ///
 /// # Arguments
@@ -77,6 +149,106 @@ pub fn get_place(chat: &Chat) -> Place {
     }
 }
 
+/// Function returns whether the chat is a supergroup with forum topics.
+///
+/// # Arguments
+///
+/// * `chat` - The chat to check.
+///
+/// # Returns
+///
+/// A `bool` indicating the chat has forum topics or not.
+pub fn is_forum(chat: &Chat) -> bool {
+    matches!(
+        chat.kind,
+        ChatKind::Public(ChatPublic {
+            kind: PublicChatKind::Supergroup(PublicChatSupergroup { is_forum: true, .. }),
+            ..
+        })
+    )
+}
+
+/// Function splits the long text into parts that fit in Telegram messages.
+/// The text is split by lines, and only the lines longer than a message are split inside.
+///
+/// # Arguments
+///
+/// * `text` - The text to split.
+///
+/// # Returns
+///
+/// A vector of the parts of the text, each no longer than `MESSAGE_MAX_LEN` characters.
+pub fn split_message(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut part = String::new();
+    let mut len = 0;
+    for line in text.split('\n') {
+        let chars = line.chars().collect::<Vec<_>>();
+        for chunk in chars
+            .chunks(MESSAGE_MAX_LEN)
+            .map(|chunk| chunk.iter().collect::<String>())
+        {
+            let chunk_len = chunk.chars().count();
+            if len > 0 && len + 1 + chunk_len > MESSAGE_MAX_LEN {
+                parts.push(std::mem::take(&mut part));
+                len = 0;
+            }
+            if len > 0 {
+                part.push('\n');
+                len += 1;
+            }
+            part += &chunk;
+            len += chunk_len;
+        }
+        if chars.is_empty() && len > 0 && len < MESSAGE_MAX_LEN {
+            part.push('\n');
+            len += 1;
+        }
+    }
+    if !part.trim().is_empty() {
+        parts.push(part);
+    }
+    parts
+}
+
+/// Function returns whether the request failed because the forum topic is deleted or closed.
+///
+/// # Arguments
+///
+/// * `err` - The error of the request.
+///
+/// # Returns
+///
+/// A `bool` indicating the message can't be posted in the topic.
+pub fn is_topic_error(err: &RequestError) -> bool {
+    match err {
+        RequestError::Api(ApiError::Unknown(description)) => {
+            let description = description.to_lowercase();
+            description.contains("thread not found")
+                || description.contains("topic_closed")
+                || description.contains("topic_deleted")
+        }
+        _ => false,
+    }
+}
+
+/// Function returns the forum topic the message was sent in.
+///
+/// # Arguments
+///
+/// * `msg` - The message to check.
+///
+/// # Returns
+///
+/// Some ID of the topic thread, or None if the message was sent in the General topic or the chat
+/// has no topics.
+pub fn get_topic(msg: &Message) -> Option<i32> {
+    match &msg.kind {
+        MessageKind::Common(common) if common.is_topic_message => msg.thread_id,
+        _ => None,
+    }
+}
+
 /// Retrieves the bot token from an environment variable or a file.
 ///
 /// The token is read from the `BIRTHDAY_REMINDER_BOT_TOKEN` environment variable.
@@ -175,8 +347,7 @@ where
     Ok(Arc::new(RwLock::new(data)))
 }
 
-/// Parses the input string to create a `Birthday` struct.
-/// The input string should be in the format "name, date, @username" or "name, date".
+/// Parses the input string to create a birthday index.
 ///
 /// # Arguments
 ///
@@ -184,28 +355,14 @@ where
 ///
 /// # Returns
 ///
-/// A `Birthday` struct if the input is valid, otherwise `None`.
-pub fn parse_birthday_info(input: &str) -> Option<Birthday> {
-    let re = Regex::new(r"^(?P<name>\w+\s?\w*), (?P<date>\d{2}-\d{2})(, @?(?P<username>\w+))?$")
-        .unwrap();
-    if let Some(caps) = re.captures(input) {
-        let name = caps.name("name").unwrap().as_str().to_string();
-        let date = caps.name("date").unwrap().as_str().to_string();
-        let username = caps
-            .name("username")
-            .map(|u| format!("@{}", u.as_str()))
-            .unwrap_or_default();
-        Some(Birthday {
-            name,
-            date,
-            username,
-        })
-    } else {
-        None
-    }
+/// A `usize` representing the birthday index if the input is valid, otherwise `None`.
+pub fn parse_birthday_index(input: &str) -> Option<usize> {
+    input.parse::<usize>().ok()
 }
 
-/// Parses the input string to create a birthday index.
+/// Parses the input string to create a month number.
+/// The input can be a month number, or a Russian or English month name, including the genitive
+/// Russian form (e.g. "марта") and three-letter abbreviations.
 ///
 /// # Arguments
 ///
@@ -213,7 +370,49 @@ pub fn parse_birthday_info(input: &str) -> Option<Birthday> {
 ///
 /// # Returns
 ///
-/// A `usize` representing the birthday index if the input is valid, otherwise `None`.
-pub fn parse_birthday_index(input: &str) -> Option<usize> {
-    input.parse::<usize>().ok()
+/// A `u32` representing the month number starting from 1 if the input is valid, otherwise `None`.
+pub fn parse_month(input: &str) -> Option<u32> {
+    const MONTHS: [&[&str]; 12] = [
+        &["январь", "января", "янв", "january", "jan"],
+        &["февраль", "февраля", "фев", "february", "feb"],
+        &["март", "марта", "мар", "march", "mar"],
+        &["апрель", "апреля", "апр", "april", "apr"],
+        &["май", "мая", "may"],
+        &["июнь", "июня", "июн", "june", "jun"],
+        &["июль", "июля", "июл", "july", "jul"],
+        &["август", "августа", "авг", "august", "aug"],
+        &["сентябрь", "сентября", "сен", "september", "sep"],
+        &["октябрь", "октября", "окт", "october", "oct"],
+        &["ноябрь", "ноября", "ноя", "november", "nov"],
+        &["декабрь", "декабря", "дек", "december", "dec"],
+    ];
+
+    let input = input.trim().trim_end_matches('.').to_lowercase();
+    if let Ok(month) = input.parse::<u32>() {
+        return (1..=12).contains(&month).then_some(month);
+    }
+
+    MONTHS
+        .iter()
+        .position(|names| names.contains(&input.as_str()))
+        .map(|idx| idx as u32 + 1)
+}
+
+/// Normalizes the input string for case-insensitive search.
+/// The string is converted to lowercase, and the Cyrillic letter "ё" is folded into "е".
+///
+/// # Arguments
+///
+/// * `input` - The input string to normalize.
+///
+/// # Returns
+///
+/// A normalized `String`.
+pub fn normalize_text(input: &str) -> String {
+    input
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c == 'ё' { 'е' } else { c })
+        .collect()
 }
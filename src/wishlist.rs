@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serde::{Deserialize, Deserializer, Serialize};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, UserId};
+
+use crate::greeting::GreetingKind;
+use crate::i18n::fill;
+use crate::{Birthday, BirthdaysMap, Language};
+
+/// The maximum number of items in a wishlist.
+pub const MAX_WISHLIST_ITEMS: usize = 20;
+
+/// The maximum length of a wishlist item in characters.
+pub const ITEM_MAX_LEN: usize = 300;
+
+/// The maximum length of an item shown on a button in characters.
+const BUTTON_ITEM_LEN: usize = 30;
+
+/// The prefix of the callback data of the wishlist buttons.
+const CALLBACK_PREFIX: &str = "wishlist";
+
+/// Represents an item of a wishlist.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WishlistItem {
+    /// The ID of the item, unique within the wishlist and never reused, so the buttons posted
+    /// earlier keep pointing to the same item after other items are removed.
+    #[serde(default)]
+    pub id: u64,
+    /// The text of the item, e.g. a description or a link.
+    pub text: String,
+    /// The Telegram user ID of the member who is going to give this gift.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed_by: Option<UserId>,
+}
+
+/// Represents the action of the wishlist button.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum WishlistAction {
+    /// Shows the wishlist of the person.
+    Show,
+    /// Claims or unclaims the item with the given ID.
+    Claim(u64),
+}
+
+impl WishlistAction {
+    /// Returns the callback data of the button with the action for the wishlist of the person.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The Telegram user ID of the person.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the callback data, e.g. "wishlist:claim:123:1767225600000".
+    pub fn callback_data(&self, owner: UserId) -> String {
+        match self {
+            WishlistAction::Show => format!("{}:show:{}", CALLBACK_PREFIX, owner),
+            WishlistAction::Claim(id) => {
+                format!("{}:claim:{}:{}", CALLBACK_PREFIX, owner, id)
+            }
+        }
+    }
+
+    /// Parses the callback data of the wishlist button.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The callback data.
+    ///
+    /// # Returns
+    ///
+    /// Some action with the Telegram user ID of the person if the data belongs to a wishlist
+    /// button, otherwise None.
+    pub fn parse(data: &str) -> Option<(WishlistAction, UserId)> {
+        let mut parts = data.split(':');
+        if parts.next()? != CALLBACK_PREFIX {
+            return None;
+        }
+        let action = parts.next()?;
+        let owner = UserId(parts.next()?.parse::<u64>().ok()?);
+        let action = match action {
+            "show" => WishlistAction::Show,
+            "claim" => WishlistAction::Claim(parts.next()?.parse::<u64>().ok()?),
+            _ => return None,
+        };
+        Some((action, owner))
+    }
+}
+
+/// Assigns the IDs to the new items of the wishlist and to the items saved before the items had
+/// IDs. The IDs are based on the current time, so an ID of a removed item is not given to a new
+/// one.
+///
+/// # Arguments
+///
+/// * `items` - The items of the wishlist, the new ones with the ID 0.
+pub fn assign_ids(items: &mut [WishlistItem]) {
+    let now = u64::try_from(Utc::now().timestamp_millis()).unwrap_or_default();
+    let first = items
+        .iter()
+        .map(|item| item.id + 1)
+        .max()
+        .unwrap_or_default()
+        .max(now);
+    for (id, item) in (first..).zip(items.iter_mut().filter(|item| item.id == 0)) {
+        item.id = id;
+    }
+}
+
+/// Deserializes the wishlists and assigns the IDs to the items saved before the items had IDs.
+///
+/// # Arguments
+///
+/// * `deserializer` - The deserializer of the backup.
+///
+/// # Returns
+///
+/// A `Result` with the map of Telegram user IDs to wishlists.
+pub fn deserialize_wishlists<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<UserId, Vec<WishlistItem>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut wishlists = HashMap::<UserId, Vec<WishlistItem>>::deserialize(deserializer)?;
+    for items in wishlists.values_mut() {
+        assign_ids(items);
+    }
+    Ok(wishlists)
+}
+
+/// Returns the items of the wishlist nobody has claimed yet with their indexes.
+///
+/// # Arguments
+///
+/// * `items` - The items of the wishlist.
+///
+/// # Returns
+///
+/// A list of unclaimed items paired with their indexes in the wishlist.
+pub fn unclaimed(items: &[WishlistItem]) -> Vec<(usize, &WishlistItem)> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.claimed_by.is_none())
+        .collect()
+}
+
+/// Returns the text of the wishlist shown to the members of the chat.
+/// The claimed items are hidden, so neither the person nor other members see them.
+///
+/// # Arguments
+///
+/// * `name` - The name of the person.
+/// * `items` - The items of the wishlist.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// A `String` with the numbered list of unclaimed items.
+pub fn text(name: &str, items: &[WishlistItem], lang: Language) -> String {
+    let tr = lang.messages();
+    let items = unclaimed(items);
+    if items.is_empty() {
+        return fill(tr.wishlist_empty, &[("name", &name)]);
+    }
+
+    let list = items
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, item))| format!("{}. {}\n", idx + 1, item.text))
+        .collect::<String>();
+    format!(
+        "{}\n{}\n{}",
+        fill(tr.wishlist_header, &[("name", &name)]),
+        list,
+        tr.wishlist_hint
+    )
+}
+
+/// Returns the inline keyboard with the buttons to claim the unclaimed items of the wishlist.
+///
+/// # Arguments
+///
+/// * `owner` - The Telegram user ID of the person.
+/// * `items` - The items of the wishlist.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// An `InlineKeyboardMarkup` with one button per unclaimed item.
+pub fn keyboard(owner: UserId, items: &[WishlistItem], lang: Language) -> InlineKeyboardMarkup {
+    let tr = lang.messages();
+    InlineKeyboardMarkup::new(
+        unclaimed(items)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (_, item))| {
+                let mut text = item.text.chars().take(BUTTON_ITEM_LEN).collect::<String>();
+                if item.text.chars().count() > BUTTON_ITEM_LEN {
+                    text += "…";
+                }
+                vec![InlineKeyboardButton::callback(
+                    fill(
+                        tr.wishlist_claim_button,
+                        &[("index", &(idx + 1)), ("item", &text)],
+                    ),
+                    WishlistAction::Claim(item.id).callback_data(owner),
+                )]
+            }),
+    )
+}
+
+/// Returns the inline keyboard with the buttons to show the wishlists of the people from the
+/// advance birthday reminders.
+///
+/// # Arguments
+///
+/// * `birthdays` - The birthdays to remind about with the kinds of the reminders.
+/// * `b_map` - The map with the wishlists.
+/// * `lang` - The language of the chat.
+///
+/// # Returns
+///
+/// Some `InlineKeyboardMarkup` if any person from the advance reminders has unclaimed wishes,
+/// otherwise None.
+pub fn show_keyboard(
+    birthdays: &[(GreetingKind, &Birthday)],
+    b_map: &BirthdaysMap,
+    lang: Language,
+) -> Option<InlineKeyboardMarkup> {
+    let tr = lang.messages();
+    let buttons = birthdays
+        .iter()
+        .filter(|(kind, birthday)| *kind == GreetingKind::Advance && birthday.kind.is_birthday())
+        .filter_map(|(_, birthday)| {
+            let owner = birthday.user_id?;
+            if unclaimed(b_map.wishlist(&owner)).is_empty() {
+                return None;
+            }
+            Some(vec![InlineKeyboardButton::callback(
+                fill(tr.wishlist_show_button, &[("name", &birthday.name)]),
+                WishlistAction::Show.callback_data(owner),
+            )])
+        })
+        .collect::<Vec<_>>();
+
+    if buttons.is_empty() {
+        None
+    } else {
+        Some(InlineKeyboardMarkup::new(buttons))
+    }
+}